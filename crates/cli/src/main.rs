@@ -1,20 +1,36 @@
-use clap::{Parser, Subcommand};
-use nve_core::domain::version::ParsedVersion;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use clap::{Args, Parser, Subcommand};
+use nve_core::config::NveConfig;
+use nve_core::constants::{NETRC_ENV, NETRC_FILE};
+use nve_core::domain::artifact::{Libc, Target};
+use nve_core::domain::mirror::Mirror;
+use nve_core::domain::version::{matches_semver, ParsedVersion};
 use nve_core::error::NveError;
-use nve_core::ports::{archive::Archive, fs::FileSystem, http::HttpClient, platform::Platform};
-use nve_core::services::{InstallService, ResolveService};
+use nve_core::ports::{
+    archive::Archive, fs::FileSystem, http::HttpClient, platform::Platform,
+    signature::SignatureVerifier,
+};
+use nve_core::services::{
+    BundleService, CacheService, CurrentService, InstallService, LocalSource,
+    OfflineInstallService, ProjectVersionService, ResolveService,
+};
+use nve_core::state::install_meta::InstallMeta;
 use nve_core::state::layout::NveLayout;
+use nve_core::state::lock::acquire_lock;
 
+use nve_infra::bundle::TarBundler;
+use nve_infra::console::ConsoleReporter;
 use nve_infra::fs_std::StdFs;
+use nve_infra::gpgv::GpgvVerifier;
 use nve_infra::http_client::ReqwestHttp;
 
-#[cfg(unix)]
-use nve_infra::archive::TarXzArchive as HostArchive;
+use nve_infra::archive::NodeArchive;
+
 #[cfg(unix)]
 use nve_infra::platform::UnixPlatform as HostPlatform;
 
-#[cfg(windows)]
-use nve_infra::archive::ZipArchive as HostArchive;
 #[cfg(windows)]
 use nve_infra::platform::WindowsPlatform as HostPlatform;
 
@@ -26,6 +42,26 @@ use nve_infra::platform::WindowsPlatform as HostPlatform;
     about = "Node.js version manager (without system privileges)"
 )]
 struct Cli {
+    /// Segundos a esperar si otro proceso nve tiene el lock (0 = fallar de inmediato)
+    #[arg(long, global = true, value_name = "SECS")]
+    lock_timeout: Option<u64>,
+
+    /// Mirror de https://nodejs.org/dist (también NVE_NODEJS_ORG_MIRROR / NVM_NODEJS_ORG_MIRROR)
+    #[arg(long, global = true, value_name = "URL")]
+    mirror: Option<String>,
+
+    /// URL del index.json de releases, si no es <mirror>/index.json
+    #[arg(long, global = true, value_name = "URL")]
+    index_url: Option<String>,
+
+    /// Reintentos ante fallos de red transitorios (0 = sin reintentos)
+    #[arg(long, global = true, value_name = "N")]
+    retries: Option<u32>,
+
+    /// Espera inicial entre reintentos en milisegundos; se duplica en cada intento
+    #[arg(long, global = true, value_name = "MS")]
+    retry_delay: Option<u64>,
+
     #[command(subcommand)]
     cmd: Commands,
 }
@@ -34,56 +70,298 @@ struct Cli {
 enum Commands {
     #[command(alias = "add")]
     Install {
-        spec: String,
+        /// Versión a instalar; si se omite se lee de .nvmrc, .node-version o package.json
+        spec: Option<String>,
+        /// Verifica la firma OpenPGP de SHASUMS256.txt con el keyring de ~/.nve/keys
+        #[arg(long)]
+        verify_signature: bool,
+        /// Instala sin red desde un archivo oficial (node-v<versión>-<os>-<arch>.tar.xz o .zip)
+        #[arg(
+            long,
+            value_name = "ARCHIVE",
+            conflicts_with_all = ["spec", "from_dir", "os", "arch", "libc"]
+        )]
+        from_file: Option<PathBuf>,
+        /// Instala sin red desde un directorio ya extraído
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["spec", "os", "arch", "libc"])]
+        from_dir: Option<PathBuf>,
+        #[command(flatten)]
+        target: TargetArgs,
     },
     #[command(alias = "uninstall")]
     Remove {
         spec: String,
+        #[command(flatten)]
+        target: TargetArgs,
     },
     List,
+    /// Muestra la versión activa, comprobada con node --version
+    Current,
     Use {
-        spec: String,
+        /// Versión a activar; si se omite se lee de .nvmrc, .node-version o package.json
+        spec: Option<String>,
     },
     Remote {
         spec: String,
+        #[command(flatten)]
+        target: TargetArgs,
+    },
+    /// Gestiona el keyring de claves de release de Node.js
+    Keys {
+        #[command(subcommand)]
+        cmd: KeysCommands,
+    },
+    /// Empaqueta versiones para instalarlas en máquinas sin red
+    Bundle {
+        #[command(subcommand)]
+        cmd: BundleCommands,
+    },
+    /// Gestiona la caché de archivos descargados (~/.nve/cache/downloads)
+    Cache {
+        #[command(subcommand)]
+        cmd: CacheCommands,
+    },
+}
+
+/// Plataforma de destino (`--os`/`--arch`); por defecto, la del host.
+#[derive(Args, Debug)]
+struct TargetArgs {
+    /// Sistema de destino (linux, darwin, win...); por defecto, el actual
+    #[arg(long)]
+    os: Option<String>,
+    /// Arquitectura de destino (x64, arm64, armv7l, ppc64le, s390x...); por defecto, la actual
+    #[arg(long)]
+    arch: Option<String>,
+    /// Libc de destino en Linux; por defecto, la detectada en el host
+    #[arg(long, value_parser = ["glibc", "musl"])]
+    libc: Option<String>,
+}
+
+impl TargetArgs {
+    fn target<P: Platform>(&self, plat: &P) -> Target {
+        let host = plat.target();
+        let os = self.os.clone().unwrap_or(host.os.clone());
+        let libc = match self.libc.as_deref() {
+            Some("musl") => Libc::Musl,
+            Some(_) => Libc::Glibc,
+            // musl solo tiene sentido en Linux: con otro `--os` no se hereda del host.
+            None if os == host.os => host.libc,
+            None => Libc::Glibc,
+        };
+        Target {
+            os,
+            arch: self.arch.clone().unwrap_or(host.arch),
+            libc,
+        }
+    }
+
+    /// La plataforma pedida si no es la del host.
+    fn requested<P: Platform>(&self, plat: &P) -> Option<Target> {
+        Some(self.target(plat)).filter(|t| *t != plat.target())
+    }
+
+    /// La plataforma pedida si el host no puede ejecutarla (instalada en `targets/`);
+    /// `None` trabaja como siempre sobre `versions/`.
+    fn foreign<P: Platform>(&self, plat: &P) -> Option<Target> {
+        self.requested(plat).filter(|t| !plat.can_run(t))
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum BundleCommands {
+    /// Descarga las versiones indicadas (y sus SHASUMS) en un único fichero
+    Export {
+        #[arg(required = true)]
+        specs: Vec<String>,
+        #[command(flatten)]
+        target: TargetArgs,
+        #[arg(short, long, value_name = "FILE", default_value = "node-bundle.tar")]
+        output: PathBuf,
+        /// Verifica la firma OpenPGP de cada SHASUMS256.txt antes de empaquetarlo
+        #[arg(long)]
+        verify_signature: bool,
+    },
+    /// Instala las versiones de un bundle sin acceder a la red
+    Import {
+        file: PathBuf,
+        /// Verifica sin red la firma de los SHASUMS256.txt incluidos en el bundle
+        #[arg(long)]
+        verify_signature: bool,
+    },
+}
+
+impl BundleCommands {
+    fn verify_signature(&self) -> bool {
+        match self {
+            Self::Export {
+                verify_signature, ..
+            }
+            | Self::Import {
+                verify_signature, ..
+            } => *verify_signature,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheCommands {
+    /// Lista los archivos en caché
+    #[command(alias = "list")]
+    Ls,
+    /// Borra todos los archivos en caché
+    Clean,
+    /// Borra los archivos descargados hace más de la antigüedad indicada
+    Prune {
+        /// Antigüedad: número con sufijo s, m, h, d o w (p. ej. 30d); sin sufijo, días
+        #[arg(long, value_name = "AGE", value_parser = parse_age)]
+        older_than: Duration,
     },
 }
 
+#[derive(Subcommand, Debug)]
+enum KeysCommands {
+    /// Reemplaza el keyring por un fichero local (formato binario de gpg, p. ej. pubring.kbx)
+    Import { file: PathBuf },
+}
+
 #[tokio::main]
 async fn main() -> Result<(), NveError> {
     let cli = Cli::parse();
 
-    let base = dirs::home_dir()
-        .ok_or(NveError::HomeDirNotFound)?
-        .join(".nve");
-    let layout = NveLayout { base };
+    let home = dirs::home_dir().ok_or(NveError::HomeDirNotFound)?;
+    let layout = NveLayout {
+        base: home.join(".nve"),
+    };
 
-    let httpc = ReqwestHttp::default();
     let fs = StdFs::new();
     let plat = HostPlatform::new()?;
-    let arch = HostArchive::new()?;
+    let arch = NodeArchive::new()?;
+    let mut config = NveConfig::load(&fs, &layout)?;
+    if let Some(retries) = cli.retries {
+        config.http.retries = retries;
+    }
+    if let Some(delay) = cli.retry_delay {
+        config.http.retry_delay_ms = delay;
+    }
+    let mirror = config.mirror(cli.mirror, cli.index_url);
+    let netrc = std::env::var_os(NETRC_ENV).map_or_else(|| home.join(NETRC_FILE), PathBuf::from);
+    let credentials = config.mirror_credentials(&fs, &mirror, &netrc)?;
+    let httpc = ReqwestHttp::new(&config.http, credentials)?;
+    let settings = Settings {
+        reporter: ConsoleReporter::new(),
+        lock_timeout: Duration::from_secs(cli.lock_timeout.unwrap_or(config.lock_timeout_secs)),
+        index_ttl: Duration::from_secs(config.index_ttl_secs),
+        mirror,
+        target: plat.target(),
+        verify_signatures: config.verify_signatures,
+    };
 
     match cli.cmd {
-        Commands::Install { spec } => {
-            cmd_install(&httpc, &fs, &plat, &arch, &layout, &spec).await?
+        Commands::Install {
+            from_file: Some(file),
+            ..
+        } => {
+            cmd_install_offline(
+                &fs,
+                &plat,
+                &arch,
+                &layout,
+                &settings,
+                LocalSource::Archive(&file),
+            )
+            .await?
+        }
+        Commands::Install {
+            from_dir: Some(dir),
+            ..
+        } => {
+            cmd_install_offline(
+                &fs,
+                &plat,
+                &arch,
+                &layout,
+                &settings,
+                LocalSource::Dir(&dir),
+            )
+            .await?
+        }
+        Commands::Install {
+            spec,
+            verify_signature,
+            target,
+            ..
+        } => {
+            let opts = InstallOptions {
+                spec: spec_or_project(&fs, spec)?,
+                verify_signature: verify_signature || config.verify_signatures,
+                target: target.requested(&plat),
+                emulation_fallback: config.emulation_fallback,
+            };
+            cmd_install(&httpc, &fs, &plat, &arch, &layout, &settings, &opts).await?
+        }
+        Commands::Remove { spec, target } => {
+            let target = target.foreign(&plat);
+            cmd_remove(
+                &httpc,
+                &fs,
+                &plat,
+                &layout,
+                &spec,
+                &settings,
+                target.as_ref(),
+            )
+            .await?
+        }
+        Commands::List => cmd_list(&fs, &plat, &layout, &settings).await?,
+        Commands::Current => cmd_current(&fs, &plat, &layout, &settings).await?,
+        Commands::Use { spec } => {
+            let spec = spec_or_project(&fs, spec)?;
+            cmd_use(&httpc, &fs, &plat, &layout, &spec, &settings).await?
+        }
+        Commands::Remote { spec, target } => {
+            let target = target.requested(&plat);
+            cmd_remote(&httpc, &fs, &layout, &spec, &settings, target.as_ref()).await?
         }
-        Commands::Remove { spec } => cmd_remove(&fs, &plat, &layout, &spec).await?,
-        Commands::List => cmd_list(&fs, &layout).await?,
-        Commands::Use { spec } => cmd_use(&fs, &plat, &layout, &spec).await?,
-        Commands::Remote { spec } => cmd_remote(&httpc, &spec).await?,
+        Commands::Keys {
+            cmd: KeysCommands::Import { file },
+        } => cmd_keys_import(&fs, &layout, &file)?,
+        Commands::Bundle { cmd } => {
+            cmd_bundle(&httpc, &fs, &plat, &arch, &layout, &settings, cmd).await?
+        }
+        Commands::Cache { cmd } => cmd_cache(&fs, &layout, cmd)?,
     }
 
     Ok(())
 }
 
+/// Ajustes comunes resueltos a partir de flags y `config.json`.
+struct Settings {
+    reporter: ConsoleReporter,
+    lock_timeout: Duration,
+    index_ttl: Duration,
+    mirror: Mirror,
+    /// Plataforma del host, libc incluida.
+    target: Target,
+    /// `verify_signatures` de la config; los flags `--verify-signature` solo lo activan.
+    verify_signatures: bool,
+}
+
 // Commands
+struct InstallOptions {
+    spec: String,
+    verify_signature: bool,
+    target: Option<Target>,
+    emulation_fallback: bool,
+}
+
 async fn cmd_install<H, F, P, A>(
     http: &H,
     fs: &F,
     plat: &P,
     arch: &A,
     layout: &NveLayout,
-    spec_str: &str,
+    settings: &Settings,
+    opts: &InstallOptions,
 ) -> Result<(), NveError>
 where
     H: HttpClient,
@@ -91,45 +369,98 @@ where
     P: Platform,
     A: Archive,
 {
-    let spec = parse_spec(spec_str)?;
+    let spec = parse_spec(&opts.spec)?;
+    let verifier = signature_verifier(layout, opts.verify_signature)?;
     let svc = InstallService {
         http,
         fs,
         plat,
         arch,
         layout,
+        verifier: verifier.as_ref().map(|v| v as &dyn SignatureVerifier),
+        lock_timeout: settings.lock_timeout,
+        reporter: &settings.reporter,
+        index_ttl: settings.index_ttl,
+        mirror: &settings.mirror,
+        target: opts.target.as_ref(),
+        emulation_fallback: opts.emulation_fallback,
     };
     let exact = svc.install(&spec).await?;
+    match &opts.target {
+        Some(target) => println!("Installed {} for {}", exact, target),
+        None => println!("Installed {}", exact),
+    }
+    Ok(())
+}
+
+async fn cmd_install_offline<F, P, A>(
+    fs: &F,
+    plat: &P,
+    arch: &A,
+    layout: &NveLayout,
+    settings: &Settings,
+    source: LocalSource<'_>,
+) -> Result<(), NveError>
+where
+    F: FileSystem,
+    P: Platform,
+    A: Archive,
+{
+    let svc = OfflineInstallService {
+        fs,
+        plat,
+        arch,
+        layout,
+        lock_timeout: settings.lock_timeout,
+    };
+    let exact = svc.install(source).await?;
     println!("Installed {}", exact);
     Ok(())
 }
 
-async fn cmd_remove<F, P>(
+async fn cmd_remove<H, F, P>(
+    http: &H,
     fs: &F,
     plat: &P,
     layout: &NveLayout,
     spec_str: &str,
+    settings: &Settings,
+    target: Option<&Target>,
 ) -> Result<(), NveError>
 where
+    H: HttpClient,
     F: FileSystem,
     P: Platform,
 {
     let spec = parse_spec(spec_str)?;
-    let versions = fs.read_dir_names(&layout.versions_dir())?;
-    let exact = resolve_installed(&versions, &spec)
+    let versions_dir = match target {
+        Some(target) => layout.target_versions_dir(target),
+        None => layout.versions_dir(),
+    };
+    let versions = fs.read_dir_names(&versions_dir)?;
+    let exact = resolve_installed(&resolver(http, fs, layout, settings), &versions, &spec)
+        .await?
         .ok_or_else(|| NveError::VersionNotInstalled(spec.full_version.clone()))?;
-    let version_dir = layout.version_dir(&exact);
+    let version_dir = versions_dir.join(&exact);
 
-    if plat
-        .is_current(&exact, &layout.current_dir())
-        .await
-        .unwrap_or(false)
-    {
-        let cur = layout.current_dir();
-        if fs.exists(&cur) {
-            fs.remove_dir_all(&cur)?;
-            fs.create_dir_all(&cur)?;
-        }
+    let _version_lock =
+        acquire_lock(fs, &layout.version_lock_path(&exact), settings.lock_timeout).await?;
+    if let Some(target) = target {
+        // Las instalaciones de otra plataforma nunca son la versión actual.
+        fs.remove_dir_all(&version_dir)?;
+        println!("Removed {} for {}", exact, target);
+        return Ok(());
+    }
+    let _lock = acquire_lock(fs, &layout.lock_path(), settings.lock_timeout).await?;
+
+    let current = CurrentService {
+        fs,
+        plat,
+        layout,
+        reporter: &settings.reporter,
+    };
+    if current.is_current(&exact).await? {
+        current.clear()?;
     }
 
     fs.remove_dir_all(&version_dir)?;
@@ -137,9 +468,15 @@ where
     Ok(())
 }
 
-async fn cmd_list<F>(fs: &F, layout: &NveLayout) -> Result<(), NveError>
+async fn cmd_list<F, P>(
+    fs: &F,
+    plat: &P,
+    layout: &NveLayout,
+    settings: &Settings,
+) -> Result<(), NveError>
 where
     F: FileSystem,
+    P: Platform,
 {
     let mut versions = if fs.exists(&layout.versions_dir()) {
         fs.read_dir_names(&layout.versions_dir())?
@@ -147,14 +484,57 @@ where
         vec![]
     };
     versions.sort();
+    let host = plat.target();
+    let current = CurrentService {
+        fs,
+        plat,
+        layout,
+        reporter: &settings.reporter,
+    }
+    .get()
+    .await?;
     for v in versions {
-        println!("{v}");
+        let mut tags = Vec::new();
+        // Builds de otra plataforma que el host ejecuta emulados (x64 con Rosetta).
+        if let Some(meta) = InstallMeta::read(fs, &layout.version_dir(&v)) {
+            if meta.target != host {
+                tags.push(format!("{}, emulated", meta.target));
+            }
+        }
+        let is_current = current.as_deref() == Some(v.as_str());
+        if is_current {
+            tags.push("current".to_string());
+        }
+        let marker = if is_current { "*" } else { " " };
+        if tags.is_empty() {
+            println!("{marker} {v}");
+        } else {
+            println!("{marker} {v} ({})", tags.join(", "));
+        }
+    }
+
+    let mut targets = fs.read_dir_names(&layout.targets_dir())?;
+    targets.sort();
+    for target in targets {
+        let mut versions = fs.read_dir_names(&layout.targets_dir().join(&target))?;
+        versions.sort();
+        for v in versions {
+            println!("  {v} ({target})");
+        }
     }
     Ok(())
 }
 
-async fn cmd_use<F, P>(fs: &F, plat: &P, layout: &NveLayout, spec_str: &str) -> Result<(), NveError>
+async fn cmd_use<H, F, P>(
+    http: &H,
+    fs: &F,
+    plat: &P,
+    layout: &NveLayout,
+    spec_str: &str,
+    settings: &Settings,
+) -> Result<(), NveError>
 where
+    H: HttpClient,
     F: FileSystem,
     P: Platform,
 {
@@ -164,40 +544,260 @@ where
     } else {
         vec![]
     };
-    let exact = resolve_installed(&versions, &spec)
+    let exact = resolve_installed(&resolver(http, fs, layout, settings), &versions, &spec)
+        .await?
         .ok_or_else(|| NveError::VersionNotInstalled(spec.full_version.clone()))?;
-    let version_dir = layout.version_dir(&exact);
-    plat.set_current(&version_dir, &layout.current_dir())
-        .await?;
+    // Mismo orden que `remove`; con los locks tomados se comprueba que otro proceso no la
+    // haya desinstalado desde que se resolvió.
+    let _version_lock =
+        acquire_lock(fs, &layout.version_lock_path(&exact), settings.lock_timeout).await?;
+    let _lock = acquire_lock(fs, &layout.lock_path(), settings.lock_timeout).await?;
+    if !fs.exists(&layout.versions_dir().join(&exact)) {
+        return Err(NveError::VersionNotInstalled(exact));
+    }
+    CurrentService {
+        fs,
+        plat,
+        layout,
+        reporter: &settings.reporter,
+    }
+    .set(&exact)
+    .await?;
     println!("Using {}", exact);
     Ok(())
 }
 
-async fn cmd_remote<H: HttpClient>(http: &H, spec_str: &str) -> Result<(), NveError> {
+async fn cmd_current<F, P>(
+    fs: &F,
+    plat: &P,
+    layout: &NveLayout,
+    settings: &Settings,
+) -> Result<(), NveError>
+where
+    F: FileSystem,
+    P: Platform,
+{
+    let current = CurrentService {
+        fs,
+        plat,
+        layout,
+        reporter: &settings.reporter,
+    }
+    .get()
+    .await?
+    .ok_or(NveError::NoCurrentVersion)?;
+    println!("{current}");
+    Ok(())
+}
+
+async fn cmd_remote<H, F>(
+    http: &H,
+    fs: &F,
+    layout: &NveLayout,
+    spec_str: &str,
+    settings: &Settings,
+    target: Option<&Target>,
+) -> Result<(), NveError>
+where
+    H: HttpClient,
+    F: FileSystem,
+{
     let spec = parse_spec(spec_str)?;
-    let resolver = ResolveService { http };
+    let resolver = ResolveService {
+        target: target.unwrap_or(&settings.target),
+        ..resolver(http, fs, layout, settings)
+    };
     let exact = resolver.resolve(&spec).await?;
-    println!("{exact}");
+    match target {
+        Some(target) => println!("{exact} ({})", target.archive_name(&exact)),
+        None => println!("{exact}"),
+    }
+    Ok(())
+}
+
+fn cmd_keys_import<F: FileSystem>(fs: &F, layout: &NveLayout, file: &Path) -> Result<(), NveError> {
+    let data = fs.read_file(file)?;
+    let keyring = layout.keyring_path();
+    fs.write_file(&keyring, &data)?;
+    println!("Imported keyring into {}", keyring.display());
+    Ok(())
+}
+
+fn signature_verifier(layout: &NveLayout, enabled: bool) -> Result<Option<GpgvVerifier>, NveError> {
+    if !enabled {
+        return Ok(None);
+    }
+    GpgvVerifier::with_bundled(&layout.keyring_path(), &layout.bundled_keyring_path()).map(Some)
+}
+
+async fn cmd_bundle<H, F, P, A>(
+    http: &H,
+    fs: &F,
+    plat: &P,
+    arch: &A,
+    layout: &NveLayout,
+    settings: &Settings,
+    cmd: BundleCommands,
+) -> Result<(), NveError>
+where
+    H: HttpClient,
+    F: FileSystem,
+    P: Platform,
+    A: Archive,
+{
+    let verifier =
+        signature_verifier(layout, cmd.verify_signature() || settings.verify_signatures)?;
+    let svc = BundleService {
+        http,
+        fs,
+        plat,
+        arch,
+        bundler: &TarBundler::new(),
+        layout,
+        verifier: verifier.as_ref().map(|v| v as &dyn SignatureVerifier),
+        lock_timeout: settings.lock_timeout,
+        reporter: &settings.reporter,
+        index_ttl: settings.index_ttl,
+        mirror: &settings.mirror,
+    };
+    match cmd {
+        BundleCommands::Export {
+            specs,
+            target,
+            output,
+            ..
+        } => {
+            let specs = specs
+                .iter()
+                .map(|s| parse_spec(s))
+                .collect::<Result<Vec<_>, _>>()?;
+            let target = target.target(plat);
+            let manifest = svc.export(&specs, &target, &output).await?;
+            for entry in &manifest.versions {
+                println!("Bundled {} ({})", entry.version, entry.file);
+            }
+            println!("Wrote {}", output.display());
+        }
+        BundleCommands::Import { file, .. } => {
+            for version in svc.import(&file).await? {
+                println!("Installed {}", version);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn cmd_cache<F: FileSystem>(
+    fs: &F,
+    layout: &NveLayout,
+    cmd: CacheCommands,
+) -> Result<(), NveError> {
+    let svc = CacheService { fs, layout };
+    let removal = match cmd {
+        CacheCommands::Ls => {
+            let archives = svc.list()?;
+            for a in &archives {
+                println!("{}  {}  {}", a.file, &a.sha256[..12], human_size(a.size));
+            }
+            let total = archives.iter().map(|a| a.size).sum();
+            println!("{} archive(s), {}", archives.len(), human_size(total));
+            return Ok(());
+        }
+        CacheCommands::Clean => svc.clean()?,
+        CacheCommands::Prune { older_than } => svc.prune(older_than)?,
+    };
+    println!(
+        "Removed {} archive(s), freed {}",
+        removal.removed,
+        human_size(removal.bytes)
+    );
     Ok(())
 }
 
 // Helpers
+fn parse_age(input: &str) -> Result<Duration, String> {
+    let (num, unit) = match input.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => input.split_at(i),
+        None => (input, "d"),
+    };
+    let n: u64 = num
+        .parse()
+        .map_err(|_| format!("antigüedad inválida: '{input}'"))?;
+    let secs = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        "w" => 604_800,
+        _ => return Err(format!("unidad inválida en '{input}' (usa s, m, h, d o w)")),
+    };
+    n.checked_mul(secs)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("antigüedad demasiado grande: '{input}'"))
+}
+
+fn human_size(bytes: u64) -> String {
+    const MB: f64 = 1024.0 * 1024.0;
+    format!("{:.1} MB", bytes as f64 / MB)
+}
+
+fn spec_or_project<F: FileSystem>(fs: &F, spec: Option<String>) -> Result<String, NveError> {
+    if let Some(spec) = spec {
+        return Ok(spec);
+    }
+    let cwd = std::env::current_dir()?;
+    let found = ProjectVersionService { fs }
+        .find(&cwd)?
+        .ok_or(NveError::ProjectVersionNotFound(cwd))?;
+    println!("Found '{}' in {}", found.spec, found.source.display());
+    Ok(found.spec)
+}
+
+fn resolver<'a, H: HttpClient, F: FileSystem>(
+    http: &'a H,
+    fs: &'a F,
+    layout: &'a NveLayout,
+    settings: &'a Settings,
+) -> ResolveService<'a, H, F> {
+    ResolveService {
+        http,
+        fs,
+        layout,
+        reporter: &settings.reporter,
+        index_ttl: settings.index_ttl,
+        mirror: &settings.mirror,
+        target: &settings.target,
+    }
+}
+
 fn parse_spec(input: &str) -> Result<ParsedVersion, NveError> {
     ParsedVersion::parse(input).map_err(|_| NveError::InvalidVersionFormat(input.to_string()))
 }
 
-fn resolve_installed(installed: &[String], spec: &ParsedVersion) -> Option<String> {
+async fn resolve_installed<H: HttpClient, F: FileSystem>(
+    resolver: &ResolveService<'_, H, F>,
+    installed: &[String],
+    spec: &ParsedVersion,
+) -> Result<Option<String>, NveError> {
     use semver::Version;
+    // Los alias LTS solo se pueden resolver con los metadatos del índice remoto. No se filtra
+    // por build: lo instalado ya está en disco aunque el destino actual no tenga binarios.
+    let remote = if spec.needs_index() {
+        Some(resolver.known_versions(spec).await?)
+    } else {
+        None
+    };
     let mut best: Option<Version> = None;
     for s in installed {
         if let Ok(v) = Version::parse(s) {
-            let matches = v.major == spec.major
-                && spec.minor.is_none_or(|mn| v.minor == mn)
-                && spec.patch.is_none_or(|pt| v.patch == pt);
+            let matches = match &remote {
+                Some(remote) => remote.contains(s),
+                None => matches_semver(s, spec),
+            };
             if matches {
                 best = Some(best.map_or(v.clone(), |curr| std::cmp::max(curr, v)));
             }
         }
     }
-    best.map(|v| v.to_string())
+    Ok(best.map(|v| v.to_string()))
 }
@@ -0,0 +1,279 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::constants::{
+    MIRROR_ENV, MIRROR_PASSWORD_ENV, MIRROR_TOKEN_ENV, MIRROR_USER_ENV, NVM_MIRROR_ENV,
+};
+use crate::domain::mirror::Mirror;
+use crate::domain::netrc::find_login;
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+use crate::ports::http::{Credentials, HostCredentials};
+use crate::state::layout::NveLayout;
+
+/// Configuración persistente de nve (`~/.nve/config.json`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NveConfig {
+    /// Obliga a verificar la firma OpenPGP de `SHASUMS256.txt` en cada instalación.
+    pub verify_signatures: bool,
+    /// Segundos de espera por un lock ocupado antes de fallar (0 = fallar de inmediato).
+    pub lock_timeout_secs: u64,
+    /// Segundos durante los que el índice cacheado se usa sin revalidar.
+    pub index_ttl_secs: u64,
+    /// Mirror de `https://nodejs.org/dist` (p. ej. un repositorio remoto de Artifactory).
+    pub mirror: Option<String>,
+    /// URL explícita del `index.json`, si el mirror no lo publica en `<mirror>/index.json`.
+    pub index_url: Option<String>,
+    pub rc_mirror: Option<String>,
+    pub nightly_mirror: Option<String>,
+    /// Mirror de unofficial-builds, de donde salen los builds musl.
+    pub unofficial_mirror: Option<String>,
+    /// Token bearer para el mirror (mejor en `NVE_MIRROR_TOKEN` que en este fichero).
+    pub mirror_token: Option<Secret>,
+    pub mirror_user: Option<String>,
+    pub mirror_password: Option<Secret>,
+    /// En Apple Silicon, instala el build x64 (Rosetta) de las versiones sin build arm64.
+    pub emulation_fallback: bool,
+    pub http: HttpConfig,
+}
+
+/// Ajustes del cliente HTTP (sección `http` de `config.json`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+    /// Reintentos tras el primer intento fallido (0 = sin reintentos).
+    pub retries: u32,
+    /// Espera antes del primer reintento; se duplica en cada intento.
+    pub retry_delay_ms: u64,
+    /// Tope de espera entre reintentos.
+    pub retry_max_delay_ms: u64,
+    /// Aleatoriza la espera para que varios clientes no reintenten a la vez.
+    pub retry_jitter: bool,
+    /// Códigos HTTP que se reintentan, además de los errores de conexión y timeouts.
+    pub retry_statuses: Vec<u16>,
+    /// Proxy para todas las peticiones (`http://proxy:8080`). Si no se indica se usan
+    /// `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` del entorno, credenciales incluidas.
+    pub proxy: Option<String>,
+    pub proxy_user: Option<String>,
+    pub proxy_password: Option<Secret>,
+    /// Hosts que no pasan por `proxy`, con el formato de `NO_PROXY` (por defecto, el del entorno).
+    pub no_proxy: Option<String>,
+    /// Ficheros PEM con CAs raíz adicionales a las del sistema (p. ej. la CA corporativa).
+    pub ca_certs: Vec<PathBuf>,
+    /// Certificado cliente en PEM; la clave privada va en el mismo fichero o en `client_key`.
+    pub client_cert: Option<PathBuf>,
+    pub client_key: Option<PathBuf>,
+    pub connect_timeout_secs: u64,
+    /// Tiempo máximo sin recibir datos antes de abortar (y reintentar) la petición.
+    pub read_timeout_secs: u64,
+}
+
+/// Valor sensible leído de la config; `Debug` no lo muestra.
+#[derive(Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl Default for NveConfig {
+    fn default() -> Self {
+        Self {
+            verify_signatures: false,
+            lock_timeout_secs: 60,
+            index_ttl_secs: 3600,
+            mirror: None,
+            index_url: None,
+            rc_mirror: None,
+            nightly_mirror: None,
+            unofficial_mirror: None,
+            mirror_token: None,
+            mirror_user: None,
+            mirror_password: None,
+            emulation_fallback: false,
+            http: HttpConfig::default(),
+        }
+    }
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            retry_delay_ms: 500,
+            retry_max_delay_ms: 10_000,
+            retry_jitter: true,
+            retry_statuses: vec![408, 429, 500, 502, 503, 504],
+            proxy: None,
+            proxy_user: None,
+            proxy_password: None,
+            no_proxy: None,
+            ca_certs: Vec::new(),
+            client_cert: None,
+            client_key: None,
+            connect_timeout_secs: 30,
+            read_timeout_secs: 60,
+        }
+    }
+}
+
+impl NveConfig {
+    pub fn load<F: FileSystem>(fs: &F, layout: &NveLayout) -> Result<Self, NveError> {
+        let path = layout.config_path();
+        if !fs.exists(&path) {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_slice(&fs.read_file(&path)?)?)
+    }
+
+    /// Prioridad: flag de CLI > `NVE_NODEJS_ORG_MIRROR` > `NVM_NODEJS_ORG_MIRROR` > config.
+    pub fn mirror(&self, cli_mirror: Option<String>, cli_index_url: Option<String>) -> Mirror {
+        let defaults = Mirror::default();
+        Mirror {
+            dist: cli_mirror
+                .or_else(|| env_var(MIRROR_ENV))
+                .or_else(|| env_var(NVM_MIRROR_ENV))
+                .or_else(|| self.mirror.clone())
+                .unwrap_or(defaults.dist),
+            index: cli_index_url.or_else(|| self.index_url.clone()),
+            rc: self.rc_mirror.clone().unwrap_or(defaults.rc),
+            nightly: self.nightly_mirror.clone().unwrap_or(defaults.nightly),
+            unofficial: self
+                .unofficial_mirror
+                .clone()
+                .unwrap_or(defaults.unofficial),
+        }
+    }
+
+    /// Credenciales para los hosts propios del mirror. Prioridad: `NVE_MIRROR_TOKEN` >
+    /// `NVE_MIRROR_USER`/`NVE_MIRROR_PASSWORD` > config > entrada del host en `netrc`.
+    pub fn mirror_credentials<F: FileSystem>(
+        &self,
+        fs: &F,
+        mirror: &Mirror,
+        netrc: &Path,
+    ) -> Result<Vec<HostCredentials>, NveError> {
+        let hosts = mirror.custom_hosts();
+        if hosts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let explicit = env_var(MIRROR_TOKEN_ENV)
+            .map(Credentials::Bearer)
+            .or_else(|| {
+                env_var(MIRROR_USER_ENV).map(|user| Credentials::Basic {
+                    user,
+                    password: env_var(MIRROR_PASSWORD_ENV),
+                })
+            })
+            .or_else(|| {
+                let token = self.mirror_token.as_ref()?;
+                Some(Credentials::Bearer(token.expose().to_string()))
+            })
+            .or_else(|| {
+                self.mirror_user.clone().map(|user| Credentials::Basic {
+                    user,
+                    password: self
+                        .mirror_password
+                        .as_ref()
+                        .map(|p| p.expose().to_string()),
+                })
+            });
+        let netrc = match (&explicit, fs.exists(netrc)) {
+            (None, true) => String::from_utf8_lossy(&fs.read_file(netrc)?).into_owned(),
+            _ => String::new(),
+        };
+
+        Ok(hosts
+            .into_iter()
+            .filter_map(|host| {
+                let credentials = explicit.clone().or_else(|| {
+                    find_login(&netrc, &host).map(|l| Credentials::Basic {
+                        user: l.login,
+                        password: l.password,
+                    })
+                })?;
+                Some(HostCredentials { host, credentials })
+            })
+            .collect())
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::DiskFs;
+
+    fn basic(user: &str, password: &str) -> Credentials {
+        Credentials::Basic {
+            user: user.to_string(),
+            password: Some(password.to_string()),
+        }
+    }
+
+    // Un solo test: las variables de entorno son globales al proceso.
+    #[test]
+    fn mirror_credentials_prefer_env_then_config_then_netrc() {
+        let dir = tempfile::tempdir().unwrap();
+        let netrc = dir.path().join(".netrc");
+        std::fs::write(
+            &netrc,
+            "machine artifactory.example.com login netrc password n",
+        )
+        .unwrap();
+        let mirror = Mirror {
+            dist: "https://artifactory.example.com/nodejs/dist".to_string(),
+            ..Mirror::default()
+        };
+        let config: NveConfig =
+            serde_json::from_str(r#"{"mirror_user": "config", "mirror_password": "c"}"#).unwrap();
+        let credentials = |config: &NveConfig| {
+            let found = config.mirror_credentials(&DiskFs, &mirror, &netrc).unwrap();
+            assert!(found.iter().all(|c| c.host == "artifactory.example.com"));
+            found.into_iter().next().map(|c| c.credentials)
+        };
+        for var in [MIRROR_TOKEN_ENV, MIRROR_USER_ENV, MIRROR_PASSWORD_ENV] {
+            std::env::remove_var(var);
+        }
+
+        assert_eq!(
+            credentials(&NveConfig::default()),
+            Some(basic("netrc", "n"))
+        );
+        assert_eq!(credentials(&config), Some(basic("config", "c")));
+
+        std::env::set_var(MIRROR_USER_ENV, "env");
+        std::env::set_var(MIRROR_PASSWORD_ENV, "e");
+        assert_eq!(credentials(&config), Some(basic("env", "e")));
+
+        std::env::set_var(MIRROR_TOKEN_ENV, "token");
+        assert_eq!(
+            credentials(&config),
+            Some(Credentials::Bearer("token".to_string()))
+        );
+
+        for var in [MIRROR_TOKEN_ENV, MIRROR_USER_ENV, MIRROR_PASSWORD_ENV] {
+            std::env::remove_var(var);
+        }
+        // Los hosts de nodejs.org nunca reciben credenciales.
+        let official = NveConfig::default()
+            .mirror_credentials(&DiskFs, &Mirror::default(), &netrc)
+            .unwrap();
+        assert!(official.is_empty());
+    }
+}
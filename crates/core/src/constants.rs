@@ -1,5 +1,38 @@
 pub const VERSION_DIR: &str = "versions";
+pub const TARGETS_DIR: &str = "targets";
 pub const NODEJS_DIR: &str = "nodejs";
+pub const CURRENT_VERSION_FILE: &str = "current";
+pub const STAGING_DIR: &str = ".staging";
+pub const LOCK_FILE: &str = ".lock";
+pub const CACHE_DIR: &str = "cache";
+pub const PARTIAL_DIR: &str = "partial";
+pub const DOWNLOADS_DIR: &str = "downloads";
+pub const LOCKS_DIR: &str = ".locks";
 pub const NVE_HOME: &str = ".nve";
-pub const NODEJS_API_INDEX: &str = "https://nodejs.org/dist/index.json";
 pub const NODEJS_API_BASE: &str = "https://nodejs.org/dist";
+pub const NODEJS_RC_BASE: &str = "https://nodejs.org/download/rc";
+pub const NODEJS_NIGHTLY_BASE: &str = "https://nodejs.org/download/nightly";
+pub const NODEJS_UNOFFICIAL_BASE: &str = "https://unofficial-builds.nodejs.org/download/release";
+pub const SHASUMS_FILE: &str = "SHASUMS256.txt";
+pub const SHASUMS_SIG_FILE: &str = "SHASUMS256.txt.sig";
+pub const SHASUMS_ASC_FILE: &str = "SHASUMS256.txt.asc";
+pub const KEYS_DIR: &str = "keys";
+pub const KEYRING_FILE: &str = "nodejs-release-keys.kbx";
+pub const BUNDLED_KEYRING_FILE: &str = "bundled-release-keys.kbx";
+pub const CONFIG_FILE: &str = "config.json";
+pub const NVMRC_FILE: &str = ".nvmrc";
+pub const NODE_VERSION_FILE: &str = ".node-version";
+pub const PACKAGE_JSON_FILE: &str = "package.json";
+pub const INSTALL_META_FILE: &str = ".nve-install.json";
+pub const BUNDLE_MANIFEST_FILE: &str = "bundle.json";
+pub const BUNDLE_INDEX_FILE: &str = "index.json";
+pub const MIRROR_ENV: &str = "NVE_NODEJS_ORG_MIRROR";
+pub const NVM_MIRROR_ENV: &str = "NVM_NODEJS_ORG_MIRROR";
+pub const MIRROR_TOKEN_ENV: &str = "NVE_MIRROR_TOKEN";
+pub const MIRROR_USER_ENV: &str = "NVE_MIRROR_USER";
+pub const MIRROR_PASSWORD_ENV: &str = "NVE_MIRROR_PASSWORD";
+pub const NETRC_ENV: &str = "NETRC";
+#[cfg(not(windows))]
+pub const NETRC_FILE: &str = ".netrc";
+#[cfg(windows)]
+pub const NETRC_FILE: &str = "_netrc";
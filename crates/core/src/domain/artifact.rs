@@ -0,0 +1,91 @@
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+/// Formatos que sabe extraer `Archive`; el resto de nombres no se reconoce.
+const ARCHIVE_EXTENSIONS: [&str; 2] = [".tar.xz", ".zip"];
+const ARCHIVE_OSES: [&str; 5] = ["linux", "darwin", "win", "aix", "sunos"];
+/// Arquitecturas de Linux que solo se publican en unofficial-builds.
+const UNOFFICIAL_ARCHES: [&str; 3] = ["armv6l", "riscv64", "loong64"];
+
+/// Biblioteca C contra la que están enlazados los binarios de Linux.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Libc {
+    #[default]
+    Glibc,
+    /// Alpine y similares: solo hay builds en unofficial-builds (`linux-x64-musl`).
+    Musl,
+}
+
+/// Origen de los builds de una plataforma.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Distribution {
+    /// `https://nodejs.org/dist` (o su mirror).
+    Official,
+    /// `https://unofficial-builds.nodejs.org`: musl y arquitecturas sin soporte oficial.
+    Unofficial,
+}
+
+/// Plataforma de destino de un artefacto, con los nombres de nodejs.org
+/// (`linux`/`darwin`/`win`, `x64`/`arm64`...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Target {
+    pub os: String,
+    pub arch: String,
+    #[serde(default)]
+    pub libc: Libc,
+}
+
+impl Target {
+    pub fn archive_name(&self, version: &str) -> String {
+        let ext = if self.os == "win" { "zip" } else { "tar.xz" };
+        format!("node-v{}-{}.{}", version, self, ext)
+    }
+
+    pub fn is_musl(&self) -> bool {
+        self.libc == Libc::Musl
+    }
+
+    pub fn distribution(&self) -> Distribution {
+        let unofficial_arch = self.os == "linux" && UNOFFICIAL_ARCHES.contains(&self.arch.as_str());
+        if self.is_musl() || unofficial_arch {
+            Distribution::Unofficial
+        } else {
+            Distribution::Official
+        }
+    }
+
+    /// Clave del artefacto en el campo `files` de `index.json`: `linux-x64`,
+    /// `linux-x64-musl`, `osx-arm64-tar`, `win-x64-zip`...
+    pub fn artifact_key(&self) -> String {
+        match self.os.as_str() {
+            "darwin" => format!("osx-{}-tar", self.arch),
+            "win" => format!("win-{}-zip", self.arch),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.os, self.arch)?;
+        if self.is_musl() {
+            write!(f, "-musl")?;
+        }
+        Ok(())
+    }
+}
+
+/// Versión de un archivo oficial: `node-v20.11.1-linux-x64.tar.xz` -> `20.11.1`.
+/// Admite prereleases (`node-v22.0.0-rc.1-darwin-arm64.tar.xz`).
+pub fn version_from_archive_name(name: &str) -> Option<String> {
+    let stem = ARCHIVE_EXTENSIONS
+        .iter()
+        .find_map(|ext| name.strip_suffix(ext))?;
+    let rest = stem.strip_prefix("node-v")?;
+    rest.match_indices('-').find_map(|(i, _)| {
+        let (version, target) = (&rest[..i], &rest[i + 1..]);
+        let os = target.split('-').next()?;
+        (ARCHIVE_OSES.contains(&os) && Version::parse(version).is_ok()).then(|| version.to_string())
+    })
+}
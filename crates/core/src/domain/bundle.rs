@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::artifact::Target;
+
+/// Versión del formato de `bundle.json`; se incrementa si cambia de forma incompatible.
+pub const BUNDLE_FORMAT: u32 = 1;
+
+/// `bundle.json`: qué contiene un bundle de `nve bundle export` y para qué plataforma.
+/// Cada versión va en `v<versión>/`, junto a su `SHASUMS256.txt` y la firma de este
+/// (`.sig` o `.asc`) si la release la publica.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub format: u32,
+    pub target: Target,
+    pub versions: Vec<BundleEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleEntry {
+    pub version: String,
+    pub file: String,
+    pub sha256: String,
+}
+
+impl BundleEntry {
+    /// Directorio de la versión dentro del bundle.
+    pub fn dir(&self) -> String {
+        format!("v{}", self.version)
+    }
+}
@@ -0,0 +1,39 @@
+use semver::Version;
+
+/// Canal de distribución de Node: cada uno tiene su propio índice y directorio de descarga.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Channel {
+    #[default]
+    Release,
+    Rc,
+    Nightly,
+}
+
+impl Channel {
+    /// Canal al que pertenece una versión según su identificador de prerelease.
+    pub fn of(version: &Version) -> Option<Self> {
+        let pre = version.pre.as_str();
+        if pre.is_empty() {
+            Some(Channel::Release)
+        } else if pre.starts_with("rc") {
+            Some(Channel::Rc)
+        } else if pre.starts_with("nightly") {
+            Some(Channel::Nightly)
+        } else {
+            None
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Channel::Release => "release",
+            Channel::Rc => "rc",
+            Channel::Nightly => "nightly",
+        }
+    }
+
+    /// Las versiones de un canal nunca se mezclan con las de otro al resolver.
+    pub fn accepts(&self, version: &Version) -> bool {
+        Channel::of(version) == Some(*self)
+    }
+}
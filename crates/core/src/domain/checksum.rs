@@ -0,0 +1,88 @@
+use std::io::{self, Read};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::NveError;
+
+/// Calcula el SHA-256 de `data` en hexadecimal (minúsculas).
+pub fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
+}
+
+/// Busca el hash de `file_name` dentro de un `SHASUMS256.txt`.
+///
+/// Cada línea tiene el formato `<hash>  <archivo>`.
+pub fn find_checksum(shasums: &str, file_name: &str) -> Option<String> {
+    shasums.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        let hash = parts.next()?;
+        let name = parts.next()?.trim_start_matches('*');
+        (name == file_name).then(|| hash.to_ascii_lowercase())
+    })
+}
+
+/// SHA-256 de un flujo, sin cargarlo entero en memoria.
+pub fn sha256_hex_reader(mut reader: impl Read) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut reader, &mut hasher)?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Verifica que el contenido de `reader` coincide con el hash esperado.
+pub fn verify_checksum(reader: impl Read, expected: &str, file_name: &str) -> Result<(), NveError> {
+    let actual = sha256_hex_reader(reader)?;
+    if actual.eq_ignore_ascii_case(expected) {
+        Ok(())
+    } else {
+        Err(NveError::checksum_mismatch(file_name, expected, actual))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHASUMS: &str = "\
+aaaa1111  node-v20.11.1-linux-x64.tar.gz
+BBBB2222  node-v20.11.1-linux-x64.tar.xz
+cccc3333 *node-v20.11.1-win-x64.zip
+dddd4444  node-v20.11.1-linux-x64.tar.xz.sig
+";
+
+    #[test]
+    fn find_checksum_matches_exact_names() {
+        let find = |name| find_checksum(SHASUMS, name);
+        assert_eq!(
+            find("node-v20.11.1-linux-x64.tar.xz").as_deref(),
+            Some("bbbb2222")
+        );
+        assert_eq!(
+            find("node-v20.11.1-linux-x64.tar.gz").as_deref(),
+            Some("aaaa1111")
+        );
+        // `*` marca modo binario en sha256sum; no forma parte del nombre.
+        assert_eq!(
+            find("node-v20.11.1-win-x64.zip").as_deref(),
+            Some("cccc3333")
+        );
+        assert_eq!(find("node-v20.11.1-linux-x64"), None);
+        assert_eq!(find("node-v20.11.1-darwin-arm64.tar.xz"), None);
+        assert_eq!(find_checksum("", "node-v20.11.1-linux-x64.tar.xz"), None);
+    }
+
+    #[test]
+    fn verify_checksum_reports_both_hashes() {
+        let data = b"node";
+        let good = sha256_hex(data);
+        assert!(verify_checksum(&data[..], &good.to_uppercase(), "node").is_ok());
+        match verify_checksum(&data[..], &"0".repeat(64), "node") {
+            Err(NveError::ChecksumMismatch {
+                expected, actual, ..
+            }) => {
+                assert_eq!(expected, "0".repeat(64));
+                assert_eq!(actual, good);
+            }
+            other => panic!("se esperaba ChecksumMismatch: {other:?}"),
+        }
+    }
+}
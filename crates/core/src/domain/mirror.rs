@@ -0,0 +1,83 @@
+use crate::constants::{
+    NODEJS_API_BASE, NODEJS_NIGHTLY_BASE, NODEJS_RC_BASE, NODEJS_UNOFFICIAL_BASE,
+};
+use crate::domain::artifact::Distribution;
+use crate::domain::channel::Channel;
+
+/// URLs base de descarga por canal, sustituibles por un mirror (Artifactory, Nexus...).
+#[derive(Debug, Clone)]
+pub struct Mirror {
+    /// Equivalente a `https://nodejs.org/dist`.
+    pub dist: String,
+    /// URL explícita del `index.json` de releases; por defecto `<dist>/index.json`.
+    pub index: Option<String>,
+    pub rc: String,
+    pub nightly: String,
+    /// Equivalente a `https://unofficial-builds.nodejs.org/download/release`: releases
+    /// estables para musl y arquitecturas como `armv6l` o `riscv64`.
+    pub unofficial: String,
+}
+
+impl Default for Mirror {
+    fn default() -> Self {
+        Self {
+            dist: NODEJS_API_BASE.to_string(),
+            index: None,
+            rc: NODEJS_RC_BASE.to_string(),
+            nightly: NODEJS_NIGHTLY_BASE.to_string(),
+            unofficial: NODEJS_UNOFFICIAL_BASE.to_string(),
+        }
+    }
+}
+
+impl Mirror {
+    /// Las releases estables de plataformas no oficiales salen de unofficial-builds.
+    pub fn base_url(&self, channel: Channel, dist: Distribution) -> &str {
+        let base = match (channel, dist) {
+            (Channel::Release, Distribution::Unofficial) => &self.unofficial,
+            (Channel::Release, Distribution::Official) => &self.dist,
+            (Channel::Rc, _) => &self.rc,
+            (Channel::Nightly, _) => &self.nightly,
+        };
+        base.trim_end_matches('/')
+    }
+
+    pub fn index_url(&self, channel: Channel, dist: Distribution) -> String {
+        match (&self.index, channel, dist) {
+            (Some(index), Channel::Release, Distribution::Official) => index.clone(),
+            _ => format!("{}/index.json", self.base_url(channel, dist)),
+        }
+    }
+
+    /// Hosts configurados que no son los de nodejs.org: los únicos que reciben credenciales.
+    pub fn custom_hosts(&self) -> Vec<String> {
+        let official = [
+            NODEJS_API_BASE,
+            NODEJS_RC_BASE,
+            NODEJS_NIGHTLY_BASE,
+            NODEJS_UNOFFICIAL_BASE,
+        ]
+        .map(host_of);
+        let mut hosts: Vec<String> = Vec::new();
+        let urls = [
+            Some(&self.dist),
+            self.index.as_ref(),
+            Some(&self.rc),
+            Some(&self.nightly),
+            Some(&self.unofficial),
+        ];
+        for host in urls.into_iter().flatten().filter_map(|url| host_of(url)) {
+            if !official.contains(&Some(host.clone())) && !hosts.contains(&host) {
+                hosts.push(host);
+            }
+        }
+        hosts
+    }
+}
+
+fn host_of(url: &str) -> Option<String> {
+    reqwest::Url::parse(url)
+        .ok()?
+        .host_str()
+        .map(str::to_string)
+}
@@ -1,2 +1,8 @@
+pub mod artifact;
+pub mod bundle;
+pub mod channel;
+pub mod checksum;
+pub mod mirror;
+pub mod netrc;
 pub mod release;
 pub mod version;
@@ -0,0 +1,106 @@
+/// Credenciales de una entrada `machine` (o `default`) de un fichero `.netrc`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct NetrcLogin {
+    pub login: String,
+    pub password: Option<String>,
+}
+
+/// Busca las credenciales de `host`; si no hay entrada `machine`, usa la `default`.
+/// Los bloques `macdef` se ignoran hasta la siguiente línea en blanco.
+pub fn find_login(contents: &str, host: &str) -> Option<NetrcLogin> {
+    let mut tokens = Vec::new();
+    let mut in_macdef = false;
+    for line in contents.lines() {
+        if in_macdef {
+            in_macdef = !line.trim().is_empty();
+            continue;
+        }
+        for tok in line.split_whitespace() {
+            if tok == "macdef" {
+                in_macdef = true;
+                break;
+            }
+            tokens.push(tok);
+        }
+    }
+
+    let mut entries: Vec<(Option<&str>, NetrcLogin)> = Vec::new();
+    let mut iter = tokens.into_iter();
+    while let Some(tok) = iter.next() {
+        match tok {
+            "machine" => entries.push((iter.next(), empty_login())),
+            "default" => entries.push((None, empty_login())),
+            "login" | "password" | "account" => {
+                let (Some(value), Some((_, entry))) = (iter.next(), entries.last_mut()) else {
+                    continue;
+                };
+                match tok {
+                    "login" => entry.login = value.to_string(),
+                    "password" => entry.password = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let find = |machine: Option<&str>| {
+        entries
+            .iter()
+            .find(|(m, login)| *m == machine && !login.login.is_empty())
+            .map(|(_, login)| login.clone())
+    };
+    find(Some(host)).or_else(|| find(None))
+}
+
+fn empty_login() -> NetrcLogin {
+    NetrcLogin {
+        login: String::new(),
+        password: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETRC: &str = "\
+machine other.example.com login other password x
+default login anon password guest
+
+macdef init
+machine artifactory.example.com login macro password leaked
+
+machine artifactory.example.com
+    login ci
+    password s3cret
+";
+
+    fn login(user: &str, password: Option<&str>) -> Option<NetrcLogin> {
+        Some(NetrcLogin {
+            login: user.to_string(),
+            password: password.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn machine_entry_wins_over_default() {
+        assert!(find_login(NETRC, "artifactory.example.com") == login("ci", Some("s3cret")));
+        assert!(find_login(NETRC, "other.example.com") == login("other", Some("x")));
+    }
+
+    #[test]
+    fn unknown_host_falls_back_to_default() {
+        assert!(find_login(NETRC, "nexus.example.com") == login("anon", Some("guest")));
+        assert!(find_login("machine a.example.com login a", "b.example.com").is_none());
+    }
+
+    #[test]
+    fn macdef_bodies_are_skipped() {
+        let only_macro = "macdef init\nmachine m.example.com login macro\n";
+        assert!(find_login(only_macro, "m.example.com").is_none());
+        // Tras la línea en blanco que cierra el macdef se vuelve a leer con normalidad.
+        let after = "macdef init\necho hola\n\nmachine m.example.com login real\n";
+        assert!(find_login(after, "m.example.com") == login("real", None));
+    }
+}
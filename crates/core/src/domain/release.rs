@@ -1,8 +1,100 @@
-use serde::Deserialize;
+use semver::Version;
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Deserialize)]
+use crate::domain::version::{matches_semver, LtsAlias, ParsedVersion, VersionSpec};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeRelease {
     pub version: String,
     pub date: String,
     pub files: Vec<String>,
+    /// Nombre en clave de la línea LTS (`"Iron"`), o `None` si no es LTS.
+    #[serde(default, deserialize_with = "lts_codename")]
+    pub lts: Option<String>,
+}
+
+impl NodeRelease {
+    /// Versión sin el prefijo `v` (`20.11.1`).
+    pub fn number(&self) -> &str {
+        self.version.strip_prefix('v').unwrap_or(&self.version)
+    }
+
+    /// Si publica el artefacto `key` (`linux-x64`, `osx-arm64-tar`...). Algunos mirrors
+    /// sirven el índice sin `files`: en ese caso no se puede descartar la release.
+    pub fn has_build(&self, key: &str) -> bool {
+        self.files.is_empty() || self.files.iter().any(|f| f == key)
+    }
+}
+
+/// El índice usa `false` para releases no LTS y el nombre en clave para las LTS.
+fn lts_codename<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    let value = serde_json::Value::deserialize(d)?;
+    Ok(value.as_str().map(str::to_string))
+}
+
+/// Filtra las releases del índice (ordenado de más nueva a más antigua) que encajan en `spec`,
+/// conservando el orden original.
+pub fn matching_releases<'r>(
+    releases: &'r [NodeRelease],
+    spec: &ParsedVersion,
+) -> Vec<&'r NodeRelease> {
+    match &spec.spec {
+        VersionSpec::Partial { .. }
+        | VersionSpec::Latest
+        | VersionSpec::Range(_)
+        | VersionSpec::Exact(_) => releases
+            .iter()
+            .filter(|r| matches_semver(r.number(), spec))
+            .collect(),
+        VersionSpec::Lts(alias) => {
+            let Some(codename) = lts_line(releases, alias) else {
+                return vec![];
+            };
+            releases
+                .iter()
+                .filter(|r| {
+                    r.lts
+                        .as_deref()
+                        .is_some_and(|c| c.eq_ignore_ascii_case(&codename))
+                })
+                .collect()
+        }
+    }
+}
+
+/// La release con build para `key` más próxima a `version`: la más antigua de las
+/// posteriores (p. ej. la primera con soporte para Apple Silicon) o, si no hay, la más
+/// reciente de las anteriores.
+pub fn nearest_with_build<'r>(
+    releases: &'r [NodeRelease],
+    version: &str,
+    key: &str,
+) -> Option<&'r NodeRelease> {
+    let target = Version::parse(version).ok()?;
+    let with_build = releases
+        .iter()
+        .filter(|r| r.has_build(key))
+        .filter_map(|r| Version::parse(r.number()).ok().map(|v| (v, r)));
+    let (newer, older): (Vec<_>, Vec<_>) = with_build.partition(|(v, _)| *v > target);
+    newer
+        .into_iter()
+        .min_by(|a, b| a.0.cmp(&b.0))
+        .or_else(|| older.into_iter().max_by(|a, b| a.0.cmp(&b.0)))
+        .map(|(_, r)| r)
+}
+
+/// Traduce un alias LTS al nombre en clave de su línea.
+fn lts_line(releases: &[NodeRelease], alias: &LtsAlias) -> Option<String> {
+    let offset = match alias {
+        LtsAlias::Codename(name) => return Some(name.clone()),
+        LtsAlias::Latest => 0,
+        LtsAlias::Offset(n) => *n,
+    };
+    let mut lines: Vec<&str> = Vec::new();
+    for codename in releases.iter().filter_map(|r| r.lts.as_deref()) {
+        if !lines.iter().any(|l| l.eq_ignore_ascii_case(codename)) {
+            lines.push(codename);
+        }
+    }
+    lines.get(offset).map(|c| c.to_ascii_lowercase())
 }
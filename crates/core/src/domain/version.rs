@@ -1,68 +1,305 @@
-use semver::Version;
+use semver::{Version, VersionReq};
+
+use crate::domain::channel::Channel;
 
 #[derive(Debug, Clone)]
 pub struct ParsedVersion {
-    pub major: u64,
-    pub minor: Option<u64>,
-    pub patch: Option<u64>,
+    pub spec: VersionSpec,
+    pub channel: Channel,
     pub full_version: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionSpec {
+    /// `18`, `18.19` o `18.19.1`: coincidencia por prefijo.
+    Partial {
+        major: u64,
+        minor: Option<u64>,
+        patch: Option<u64>,
+    },
+    /// `lts`, `lts/*`, `lts/-1`, `lts/iron` o directamente `iron`.
+    Lts(LtsAlias),
+    /// `latest`, `current` o `node`: la release más reciente.
+    Latest,
+    /// Rango estilo npm (`^20.9.0`, `>=18.17 <21`, `18.x`, `16 || 18`): basta con que
+    /// encaje en una de las alternativas.
+    Range(Vec<VersionReq>),
+    /// Versión prerelease exacta (`23.0.0-nightly20240501abc`, `22.0.0-rc.1`).
+    Exact(Version),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LtsAlias {
+    /// `lts/*`: la línea LTS más reciente.
+    Latest,
+    /// `lts/-N`: N líneas LTS por detrás de la más reciente.
+    Offset(usize),
+    /// Nombre en clave, siempre en minúsculas (`iron`, `hydrogen`...).
+    Codename(String),
+}
+
 impl ParsedVersion {
     pub fn parse(input: &str) -> Result<Self, String> {
-        let parts: Vec<&str> = input.split('.').collect();
-        if parts.is_empty() || parts[0].is_empty() {
-            return Err(format!("Formato inválido: '{input}'"));
-        }
-        let major = parts[0]
-            .parse::<u64>()
-            .map_err(|_| format!("Formato inválido: '{input}'"))?;
-        let minor = if parts.len() > 1 {
-            Some(
-                parts[1]
-                    .parse::<u64>()
-                    .map_err(|_| format!("Formato inválido: '{input}'"))?,
+        let invalid = || format!("Formato inválido: '{input}'");
+        let lower = input.to_ascii_lowercase();
+
+        let (channel, spec) = if let Some((channel, rest)) = split_channel(&lower) {
+            // `rc`, `rc/22`, `nightly`, `nightly/^23`...
+            let spec = if rest.is_empty() {
+                VersionSpec::Latest
+            } else {
+                parse_partial(strip_v(rest))
+                    .or_else(|| parse_range(rest))
+                    .ok_or_else(invalid)?
+            };
+            (channel, spec)
+        } else if let Some(pre) = Version::parse(strip_v(input))
+            .ok()
+            .filter(|v| !v.pre.is_empty())
+        {
+            (
+                Channel::of(&pre).ok_or_else(invalid)?,
+                VersionSpec::Exact(pre),
             )
         } else {
-            None
-        };
-        let patch = if parts.len() > 2 {
-            Some(
-                parts[2]
-                    .parse::<u64>()
-                    .map_err(|_| format!("Formato inválido: '{input}'"))?,
+            (
+                Channel::Release,
+                parse_release_spec(input, lower).ok_or_else(invalid)?,
             )
-        } else {
-            None
         };
-        if parts.len() > 3 {
-            return Err(format!("Formato inválido: '{input}'"));
-        }
+
         Ok(Self {
-            major,
-            minor,
-            patch,
+            spec,
+            channel,
             full_version: input.to_string(),
         })
     }
+
+    /// Indica si el spec necesita los metadatos del índice remoto para resolverse.
+    pub fn needs_index(&self) -> bool {
+        matches!(self.spec, VersionSpec::Lts(_))
+    }
 }
 
-pub fn matches_semver(ver: &str, spec: &ParsedVersion) -> bool {
-    if let Ok(v) = Version::parse(ver) {
-        if v.major != spec.major {
-            return false;
-        }
-        if let Some(mn) = spec.minor {
-            if v.minor != mn {
-                return false;
+fn split_channel(lower: &str) -> Option<(Channel, &str)> {
+    [("rc", Channel::Rc), ("nightly", Channel::Nightly)]
+        .into_iter()
+        .find_map(|(name, channel)| {
+            let rest = lower.strip_prefix(name)?;
+            if rest.is_empty() {
+                Some((channel, rest))
+            } else {
+                rest.strip_prefix('/').map(|rest| (channel, rest))
             }
+        })
+}
+
+fn parse_release_spec(input: &str, lower: String) -> Option<VersionSpec> {
+    let spec = if matches!(lower.as_str(), "latest" | "current" | "node") {
+        VersionSpec::Latest
+    } else if let Some(major) = lower.strip_prefix("latest-") {
+        // `latest-20` equivale a `20`: la más reciente de esa major.
+        VersionSpec::Partial {
+            major: major.parse().ok()?,
+            minor: None,
+            patch: None,
         }
-        if let Some(p) = spec.patch {
-            if v.patch != p {
-                return false;
+    } else if matches!(lower.as_str(), "x" | "*") {
+        // Comodín npm: cualquier versión. Va antes que los nombres en clave (`x` lo sería).
+        VersionSpec::Range(vec![VersionReq::STAR])
+    } else if lower == "lts" {
+        VersionSpec::Lts(LtsAlias::Latest)
+    } else if let Some(alias) = lower.strip_prefix("lts/") {
+        VersionSpec::Lts(parse_lts_alias(alias)?)
+    } else if is_codename(&lower) {
+        VersionSpec::Lts(LtsAlias::Codename(lower))
+    } else if let Some(partial) = parse_partial(strip_v(input)) {
+        partial
+    } else {
+        parse_range(input)?
+    };
+    Some(spec)
+}
+
+fn parse_lts_alias(alias: &str) -> Option<LtsAlias> {
+    if alias == "*" {
+        return Some(LtsAlias::Latest);
+    }
+    if let Some(n) = alias.strip_prefix('-') {
+        return n.parse::<usize>().ok().map(|n| match n {
+            0 => LtsAlias::Latest,
+            n => LtsAlias::Offset(n),
+        });
+    }
+    is_codename(alias).then(|| LtsAlias::Codename(alias.to_string()))
+}
+
+/// Acepta el prefijo `v` habitual en `.nvmrc` (`v20.11.1`).
+fn strip_v(input: &str) -> &str {
+    match input.strip_prefix(['v', 'V']) {
+        Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => rest,
+        _ => input,
+    }
+}
+
+fn is_codename(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn parse_partial(input: &str) -> Option<VersionSpec> {
+    let parts: Vec<&str> = input.split('.').collect();
+    if parts.len() > 3 {
+        return None;
+    }
+    let mut nums = parts.iter().map(|p| p.parse::<u64>());
+    let major = nums.next()?.ok()?;
+    let minor = nums.next().transpose().ok()?;
+    let patch = nums.next().transpose().ok()?;
+    Some(VersionSpec::Partial {
+        major,
+        minor,
+        patch,
+    })
+}
+
+/// Convierte un rango npm a `VersionReq`s: `||` separa alternativas, los comparadores
+/// separados por espacios se combinan y `a - b` se traduce a `>=a, <=b`. Una versión sin
+/// operador es exacta (`18.17.0`) o un X-range (`18.17`), no un caret como en Cargo.
+fn parse_range(input: &str) -> Option<VersionSpec> {
+    // Como en npm, una alternativa vacía (`18 ||`) es `*`; un spec vacío no es un rango.
+    if input.trim().is_empty() {
+        return None;
+    }
+    let reqs = input
+        .split("||")
+        .map(|alt| {
+            let mut tokens: Vec<String> = Vec::new();
+            for tok in alt.split(|c: char| c.is_whitespace() || c == ',') {
+                match tokens.last_mut() {
+                    Some(prev) if is_operator(prev) => prev.push_str(tok),
+                    _ if tok.is_empty() => {}
+                    _ => tokens.push(tok.to_string()),
+                }
             }
+            let comparators = match tokens.as_slice() {
+                [lo, dash, hi] if dash == "-" => {
+                    vec![format!(">={}", strip_v(lo)), format!("<={}", strip_v(hi))]
+                }
+                [] => vec!["*".to_string()],
+                _ => tokens.iter().map(|t| bare_to_exact(t)).collect(),
+            };
+            VersionReq::parse(&comparators.join(", ")).ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(VersionSpec::Range(reqs))
+}
+
+/// `18.17.0` -> `=18.17.0`, `18.x` -> `=18.x`; `x`/`X` -> `*`. Con `=`, semver trata las
+/// partes que faltan como comodines, igual que npm.
+fn bare_to_exact(tok: &str) -> String {
+    if matches!(tok, "x" | "X" | "*") {
+        return "*".to_string();
+    }
+    let tok = strip_v(tok);
+    if tok.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("={tok}")
+    } else {
+        tok.to_string()
+    }
+}
+
+fn is_operator(tok: &str) -> bool {
+    matches!(tok, "=" | ">" | ">=" | "<" | "<=" | "~" | "^")
+}
+
+/// Comprueba si `ver` encaja en un spec numérico y en su canal. Los alias LTS nunca
+/// coinciden aquí: requieren el índice remoto (ver `ResolveService`).
+pub fn matches_semver(ver: &str, spec: &ParsedVersion) -> bool {
+    let Ok(v) = Version::parse(ver) else {
+        return false;
+    };
+    if !spec.channel.accepts(&v) {
+        return false;
+    }
+    match &spec.spec {
+        VersionSpec::Partial {
+            major,
+            minor,
+            patch,
+        } => {
+            v.major == *major
+                && minor.is_none_or(|mn| v.minor == mn)
+                && patch.is_none_or(|p| v.patch == p)
         }
-        return true;
+        VersionSpec::Latest => true,
+        VersionSpec::Range(reqs) => {
+            // Se compara sin prerelease para que `rc/^22` encaje con `22.0.0-rc.1`.
+            let core = Version::new(v.major, v.minor, v.patch);
+            reqs.iter().any(|r| r.matches(&core))
+        }
+        VersionSpec::Exact(exact) => v == *exact,
+        VersionSpec::Lts(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(spec: &str, version: &str) -> bool {
+        matches_semver(version, &ParsedVersion::parse(spec).unwrap())
+    }
+
+    #[test]
+    fn ranges_follow_npm_semantics() {
+        let cases = [
+            ("18.17.0 || 20", "18.17.0", true),
+            ("18.17.0 || 20", "18.20.0", false),
+            ("18.17.0 || 20", "20.11.1", true),
+            ("18.17 || 20", "18.17.5", true),
+            ("18.17 || 20", "18.18.0", false),
+            ("^20.9.0", "20.11.1", true),
+            ("^20.9.0", "21.0.0", false),
+            (">=18.17 <21", "20.0.0", true),
+            (">=18.17 <21", "18.16.0", false),
+            (">=18.17 <21", "21.0.0", false),
+            ("16 - 18", "18.5.0", true),
+            ("16 - 18", "19.0.0", false),
+            ("18.x", "18.19.1", true),
+            ("x", "20.11.1", true),
+            ("X", "16.0.0", true),
+            ("*", "22.1.0", true),
+            ("20 || x", "14.0.0", true),
+            ("20 ||", "14.0.0", true),
+            ("~ 20.1", "20.1.9", true),
+        ];
+        for (spec, version, expected) in cases {
+            assert_eq!(matches(spec, version), expected, "{spec} ~ {version}");
+        }
+    }
+
+    #[test]
+    fn parses_aliases_and_partials() {
+        let spec = |s: &str| ParsedVersion::parse(s).unwrap().spec;
+        assert_eq!(
+            spec("v20.11.1"),
+            VersionSpec::Partial {
+                major: 20,
+                minor: Some(11),
+                patch: Some(1)
+            }
+        );
+        assert_eq!(spec("lts"), VersionSpec::Lts(LtsAlias::Latest));
+        assert_eq!(spec("lts/-1"), VersionSpec::Lts(LtsAlias::Offset(1)));
+        assert_eq!(
+            spec("Iron"),
+            VersionSpec::Lts(LtsAlias::Codename("iron".into()))
+        );
+        assert_eq!(spec("x"), VersionSpec::Range(vec![VersionReq::STAR]));
+        assert_eq!(spec("node"), VersionSpec::Latest);
+        assert!(ParsedVersion::parse("18..1").is_err());
+        assert!(ParsedVersion::parse("").is_err());
+        assert!(ParsedVersion::parse("  ").is_err());
+        assert!(ParsedVersion::parse("~>1.2").is_err());
     }
-    false
 }
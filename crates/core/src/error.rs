@@ -21,12 +21,36 @@ pub enum NveError {
     Semver(#[from] semver::Error),
 
     // --------- Domain ---------
-    #[error("Formato de versión inválido: '{0}' (usa '18', '18.19' o '18.19.1')")]
+    #[error(
+        "Formato de versión inválido: '{0}' (usa '18', '18.19', '18.19.1', '^20.9', 'latest', 'lts/*', 'lts/iron', 'rc/22' o 'nightly')"
+    )]
     InvalidVersionFormat(String),
 
+    #[error(
+        "No se indicó versión y no hay .nvmrc, .node-version ni package.json con versión desde {0}"
+    )]
+    ProjectVersionNotFound(PathBuf),
+
     #[error("No se encontró la versión solicitada: '{0}'")]
     VersionNotFound(String),
 
+    #[error("No hay build de Node {version} para {target}{}", nearest_hint(.nearest))]
+    NoBuildForTarget {
+        version: String,
+        target: String,
+        nearest: Option<String>,
+    },
+
+    #[error(
+        "Node {version} ya está instalado para {installed}; desinstálalo con `nve remove \
+         {version}` para instalar el de {requested}"
+    )]
+    InstalledForOtherTarget {
+        version: String,
+        installed: String,
+        requested: String,
+    },
+
     #[error("La versión no está instalada: '{0}'")]
     VersionNotInstalled(String),
 
@@ -36,6 +60,18 @@ pub enum NveError {
     #[error("La ruta no pertenece a una instalación válida de Node: {0}")]
     InvalidInstallLayout(PathBuf),
 
+    #[error(
+        "No se puede deducir la versión de '{0}' (se espera node-v<versión>-<os>-<arch>.tar.xz o .zip)"
+    )]
+    UnrecognizedArchive(String),
+
+    #[error("'{origin}' declara Node {expected}, pero node --version informa {found}")]
+    LocalVersionMismatch {
+        origin: String,
+        expected: String,
+        found: String,
+    },
+
     #[error("Conflicto de concurrencia: operación en curso")]
     ConcurrencyConflict,
 
@@ -43,9 +79,43 @@ pub enum NveError {
     #[error("El artefacto remoto no está disponible (404/403): {0}")]
     ArtifactUnavailable(String),
 
+    #[error("Checksum SHA-256 inválido para '{file}': esperado {expected}, obtenido {actual}")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("No se encontró el checksum de '{0}' en SHASUMS256.txt")]
+    ChecksumNotFound(String),
+
+    #[error("Firma OpenPGP inválida para SHASUMS256.txt: {0}")]
+    SignatureInvalid(String),
+
+    #[error("No hay firma OpenPGP para SHASUMS256.txt en {0}")]
+    SignatureNotFound(String),
+
+    #[error("No se encontró el keyring de claves de release de Node: {0}")]
+    KeyringNotFound(PathBuf),
+
+    #[error(
+        "Este binario se compiló sin las claves de release de Node; impórtalas con `nve keys \
+         import <keyring>`"
+    )]
+    NoBundledKeyring,
+
+    #[error("Configuración HTTP inválida: {0}")]
+    InvalidHttpConfig(String),
+
     #[error("Error extrayendo el archivo (zip/tar.xz): {0}")]
     ExtractError(String),
 
+    #[error("Bundle inválido: {0}")]
+    InvalidBundle(String),
+
+    #[error("El bundle contiene Node para {bundle}, pero esta máquina es {host}")]
+    BundleTargetMismatch { bundle: String, host: String },
+
     // --------- Plataforma (OS) ---------
     #[error("Operación específica de la plataforma no soportada: {0}")]
     PlatformUnsupported(&'static str),
@@ -80,10 +150,26 @@ impl NveError {
         NveError::ArtifactUnavailable(url.into())
     }
 
+    pub fn invalid_http_config(msg: impl Into<String>) -> Self {
+        NveError::InvalidHttpConfig(msg.into())
+    }
+
     pub fn extract_err(msg: impl Into<String>) -> Self {
         NveError::ExtractError(msg.into())
     }
 
+    pub fn checksum_mismatch(
+        file: impl Into<String>,
+        expected: impl Into<String>,
+        actual: impl Into<String>,
+    ) -> Self {
+        NveError::ChecksumMismatch {
+            file: file.into(),
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
+
     pub fn invalid_layout(path: impl Into<PathBuf>) -> Self {
         NveError::InvalidInstallLayout(path.into())
     }
@@ -95,3 +181,10 @@ impl NveError {
         }
     }
 }
+
+fn nearest_hint(nearest: &Option<String>) -> String {
+    match nearest {
+        Some(v) => format!("; la más cercana con build es {v}"),
+        None => String::new(),
+    }
+}
@@ -1,6 +1,10 @@
+pub mod config;
 pub mod constants;
 pub mod domain;
 pub mod error;
 pub mod ports;
 pub mod services;
 pub mod state;
+
+#[cfg(test)]
+mod test_support;
@@ -4,5 +4,11 @@ use crate::error::NveError;
 
 #[async_trait::async_trait]
 pub trait Archive: Send + Sync {
-    async fn extract(&self, data: &[u8], target_dir: &Path, version: &str) -> Result<(), NveError>;
+    /// Extrae el archivo `archive` (ya descargado en disco) dentro de `target_dir`.
+    async fn extract(
+        &self,
+        archive: &Path,
+        target_dir: &Path,
+        version: &str,
+    ) -> Result<(), NveError>;
 }
@@ -0,0 +1,13 @@
+use std::path::Path;
+
+use crate::error::NveError;
+
+/// Empaqueta y desempaqueta los bundles de `nve bundle` (un único fichero, sin comprimir:
+/// los archivos de Node que contiene ya lo están).
+#[async_trait::async_trait]
+pub trait Bundler: Send + Sync {
+    /// Empaqueta el contenido de `dir` en el fichero `dest`.
+    async fn pack(&self, dir: &Path, dest: &Path) -> Result<(), NveError>;
+    /// Desempaqueta `bundle` dentro de `target_dir`.
+    async fn unpack(&self, bundle: &Path, target_dir: &Path) -> Result<(), NveError>;
+}
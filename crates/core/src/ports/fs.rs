@@ -1,11 +1,34 @@
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
+use std::time::SystemTime;
 
 use crate::error::NveError;
 
+/// Fichero regular de un directorio, con los metadatos que necesita la caché.
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub name: String,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
 pub trait FileSystem: Send + Sync {
     fn create_dir_all(&self, path: &Path) -> Result<(), NveError>;
     fn remove_dir_all(&self, path: &Path) -> Result<(), NveError>;
     fn read_dir_names(&self, path: &Path) -> Result<Vec<String>, NveError>;
+    /// Ficheros regulares de `path` (vacío si no existe).
+    fn list_files(&self, path: &Path) -> Result<Vec<FileEntry>, NveError>;
     fn exists(&self, path: &Path) -> bool;
     fn copy_dir_recursive(&self, from: &Path, to: &Path) -> Result<(), NveError>;
+    fn rename(&self, from: &Path, to: &Path) -> Result<(), NveError>;
+    /// Copia un fichero, creando los directorios padre de `to` si faltan.
+    fn copy_file(&self, from: &Path, to: &Path) -> Result<(), NveError>;
+    fn read_file(&self, path: &Path) -> Result<Vec<u8>, NveError>;
+    fn open_read(&self, path: &Path) -> Result<Box<dyn Read + Send>, NveError>;
+    fn remove_file(&self, path: &Path) -> Result<(), NveError>;
+    fn write_file(&self, path: &Path, data: &[u8]) -> Result<(), NveError>;
+    /// Intenta tomar un lock exclusivo sobre `path` sin bloquear.
+    /// Devuelve `None` si otro proceso ya lo tiene; el lock se libera al soltar el `File`.
+    fn try_lock(&self, path: &Path) -> Result<Option<File>, NveError>;
 }
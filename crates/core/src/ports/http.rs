@@ -1,5 +1,64 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
 use crate::error::NveError;
 
+/// Validadores de una respuesta cacheada (`ETag` / `Last-Modified`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// Fichero donde `HttpClient::download` guarda los validadores de una descarga parcial.
+pub fn partial_meta_path(dest: &Path) -> PathBuf {
+    let mut name = dest.as_os_str().to_owned();
+    name.push(".meta");
+    PathBuf::from(name)
+}
+
+/// Credenciales para un mirror. `Debug` nunca muestra el secreto.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credentials {
+    Bearer(String),
+    Basic {
+        user: String,
+        password: Option<String>,
+    },
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credentials::Bearer(_) => f.write_str("Bearer(***)"),
+            Credentials::Basic { user, .. } => f
+                .debug_struct("Basic")
+                .field("user", user)
+                .field("password", &"***")
+                .finish(),
+        }
+    }
+}
+
+/// Credenciales que el cliente HTTP añade solo a las peticiones dirigidas a `host`.
+#[derive(Debug, Clone)]
+pub struct HostCredentials {
+    pub host: String,
+    pub credentials: Credentials,
+}
+
+#[derive(Debug)]
+pub enum Revalidated {
+    /// `304 Not Modified`: la copia local sigue siendo válida.
+    NotModified,
+    Modified {
+        body: Vec<u8>,
+        validators: CacheValidators,
+    },
+}
+
 #[async_trait::async_trait]
 pub trait HttpClient: Send + Sync {
     async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, NveError>;
@@ -7,4 +66,22 @@ pub trait HttpClient: Send + Sync {
         &self,
         url: &str,
     ) -> Result<T, NveError>;
+    /// Descarga `url` en `dest` por bloques, sin mantener el cuerpo completo en memoria.
+    /// `on_progress` recibe los bytes descargados y el total, si el servidor lo indica.
+    ///
+    /// Si `dest` ya existe es una descarga interrumpida: se reanuda con `Range` + `If-Range`
+    /// usando los validadores de `partial_meta_path(dest)`, o se empieza de cero si el
+    /// recurso ha cambiado. Si falla a medias, `dest` y sus validadores se conservan.
+    async fn download(
+        &self,
+        url: &str,
+        dest: &Path,
+        on_progress: &(dyn Fn(u64, Option<u64>) + Send + Sync),
+    ) -> Result<(), NveError>;
+    /// GET condicional (`If-None-Match` / `If-Modified-Since`).
+    async fn get_revalidated(
+        &self,
+        url: &str,
+        validators: &CacheValidators,
+    ) -> Result<Revalidated, NveError>;
 }
@@ -1,4 +1,7 @@
 pub mod archive;
+pub mod bundle;
 pub mod fs;
 pub mod http;
 pub mod platform;
+pub mod reporter;
+pub mod signature;
@@ -1,11 +1,42 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+use crate::domain::artifact::{Libc, Target};
 use crate::error::NveError;
 
 #[async_trait::async_trait]
 pub trait Platform: Send + Sync {
     fn os_arch(&self) -> (String, String);
+    /// Libc del host, detectada en tiempo de ejecución (musl en Alpine).
+    fn libc(&self) -> Libc {
+        Libc::Glibc
+    }
+    /// Versión de glibc del host (`(2, 35)`), si se pudo detectar.
+    fn glibc_version(&self) -> Option<(u64, u64)> {
+        None
+    }
+    fn target(&self) -> Target {
+        let (os, arch) = self.os_arch();
+        Target {
+            os,
+            arch,
+            libc: self.libc(),
+        }
+    }
+    /// Plataforma que el host puede ejecutar por emulación (x64 con Rosetta en Apple Silicon).
+    fn emulated_target(&self) -> Option<Target> {
+        None
+    }
+    /// Si los builds de `target` se pueden usar como versión actual en este host.
+    fn can_run(&self, target: &Target) -> bool {
+        *target == self.target() || self.emulated_target().as_ref() == Some(target)
+    }
     fn archive_name(&self, version: &str) -> String;
     async fn set_current(&self, version_dir: &Path, current_dir: &Path) -> Result<(), NveError>;
-    async fn is_current(&self, version: &str, current_dir: &Path) -> Result<bool, NveError>;
+    /// Versión de Node del directorio actual según su `node --version`; `None` si no
+    /// contiene ninguna instalación.
+    async fn current_version(&self, current_dir: &Path) -> Result<Option<String>, NveError>;
+    /// Ruta del ejecutable de Node dentro de una instalación (`bin/node` o `node.exe`).
+    fn node_binary(&self, install_dir: &Path) -> PathBuf;
+    /// Ejecuta `node --version` de la instalación y devuelve la versión sin la `v`.
+    async fn node_version(&self, install_dir: &Path) -> Result<String, NveError>;
 }
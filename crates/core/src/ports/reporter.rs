@@ -0,0 +1,9 @@
+/// Canal para avisos al usuario; el core nunca escribe directamente en la consola.
+pub trait Reporter: Send + Sync {
+    fn warn(&self, message: &str);
+
+    /// Progreso de la descarga de `file`; `total` es `None` si se desconoce el tamaño.
+    fn download_progress(&self, _file: &str, _downloaded: u64, _total: Option<u64>) {}
+
+    fn download_finished(&self, _file: &str) {}
+}
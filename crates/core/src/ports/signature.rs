@@ -0,0 +1,11 @@
+use crate::error::NveError;
+
+/// Verifica las firmas OpenPGP de `SHASUMS256.txt`.
+pub trait SignatureVerifier: Send + Sync {
+    /// Firma separada (`SHASUMS256.txt.sig`) sobre `data`.
+    fn verify(&self, data: &[u8], signature: &[u8]) -> Result<(), NveError>;
+
+    /// Documento con firma en claro (`SHASUMS256.txt.asc`). Devuelve el texto verificado,
+    /// que es el que hay que usar: lo que rodea a la firma no está cubierto por ella.
+    fn verify_clearsigned(&self, signed: &[u8]) -> Result<Vec<u8>, NveError>;
+}
@@ -0,0 +1,255 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use semver::Version;
+
+use crate::constants::{BUNDLE_INDEX_FILE, BUNDLE_MANIFEST_FILE};
+use crate::domain::artifact::{Distribution, Target};
+use crate::domain::bundle::{BundleEntry, BundleManifest, BUNDLE_FORMAT};
+use crate::domain::channel::Channel;
+use crate::domain::checksum::{find_checksum, verify_checksum};
+use crate::domain::mirror::Mirror;
+use crate::domain::release::NodeRelease;
+use crate::domain::version::ParsedVersion;
+use crate::error::NveError;
+use crate::ports::archive::Archive;
+use crate::ports::bundle::Bundler;
+use crate::ports::fs::FileSystem;
+use crate::ports::http::HttpClient;
+use crate::ports::platform::Platform;
+use crate::ports::reporter::Reporter;
+use crate::ports::signature::SignatureVerifier;
+use crate::services::download::ArchiveFetcher;
+use crate::services::resolver::meta_path;
+use crate::services::shasums::Shasums;
+use crate::services::{LocalSource, OfflineInstallService, ResolveService};
+use crate::state::index_cache::IndexCacheMeta;
+use crate::state::layout::NveLayout;
+use crate::state::lock::{acquire_lock, LockGuard};
+
+/// `nve bundle export/import`: lleva versiones de Node a máquinas sin red en un solo fichero.
+pub struct BundleService<'a, H, F, P, A, B> {
+    pub http: &'a H,
+    pub fs: &'a F,
+    pub plat: &'a P,
+    pub arch: &'a A,
+    pub bundler: &'a B,
+    pub layout: &'a NveLayout,
+    /// Si está presente, `export` verifica la firma de cada `SHASUMS256.txt` e `import`
+    /// vuelve a hacerlo sin red con la firma que viaja en el bundle.
+    pub verifier: Option<&'a dyn SignatureVerifier>,
+    pub lock_timeout: Duration,
+    pub reporter: &'a dyn Reporter,
+    pub index_ttl: Duration,
+    pub mirror: &'a Mirror,
+}
+
+impl<'a, H, F, P, A, B> BundleService<'a, H, F, P, A, B>
+where
+    H: HttpClient,
+    F: FileSystem,
+    P: Platform,
+    A: Archive,
+    B: Bundler,
+{
+    /// Descarga (o toma de la caché) el archivo de cada spec para `target` y lo empaqueta en
+    /// `dest` con su `SHASUMS256.txt` (y su firma, si se publica) y la entrada del índice.
+    pub async fn export(
+        &self,
+        specs: &[ParsedVersion],
+        target: &Target,
+        dest: &Path,
+    ) -> Result<BundleManifest, NveError> {
+        let (_lock, work) = self.work_dir().await?;
+        let result = match self.collect(specs, target, &work).await {
+            Ok(manifest) => self.bundler.pack(&work, dest).await.map(|()| manifest),
+            Err(e) => Err(e),
+        };
+        let _ = self.fs.remove_dir_all(&work);
+        result
+    }
+
+    /// Instala todas las versiones de un bundle sin acceder a la red. Devuelve las versiones
+    /// instaladas (o que ya lo estaban).
+    pub async fn import(&self, bundle: &Path) -> Result<Vec<String>, NveError> {
+        let (_lock, work) = self.work_dir().await?;
+        let result = self.install_from(bundle, &work).await;
+        let _ = self.fs.remove_dir_all(&work);
+        result
+    }
+
+    async fn collect(
+        &self,
+        specs: &[ParsedVersion],
+        target: &Target,
+        work: &Path,
+    ) -> Result<BundleManifest, NveError> {
+        let resolver = ResolveService {
+            http: self.http,
+            fs: self.fs,
+            layout: self.layout,
+            reporter: self.reporter,
+            index_ttl: self.index_ttl,
+            mirror: self.mirror,
+            target,
+        };
+        let fetcher = ArchiveFetcher {
+            http: self.http,
+            fs: self.fs,
+            layout: self.layout,
+            reporter: self.reporter,
+        };
+        let mut entries: Vec<BundleEntry> = Vec::new();
+        let mut index: Vec<NodeRelease> = Vec::new();
+
+        for spec in specs {
+            let release = resolver
+                .available_releases(spec)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| NveError::VersionNotFound(spec.full_version.clone()))?;
+            let version = release.number().to_string();
+            if entries.iter().any(|e| e.version == version) {
+                continue;
+            }
+
+            let name = target.archive_name(&version);
+            let base_url = format!(
+                "{}/v{}",
+                self.mirror.base_url(spec.channel, target.distribution()),
+                version
+            );
+            // La firma se guarda aunque aquí no se verifique: quien importe puede exigirla.
+            let shasums = Shasums::fetch(self.http, &base_url).await?;
+            let text = shasums.verified_text(self.verifier, &base_url)?;
+            let sha256 = find_checksum(&String::from_utf8_lossy(&text), &name)
+                .ok_or_else(|| NveError::ChecksumNotFound(name.clone()))?;
+            let archive = fetcher
+                .fetch(&format!("{}/{}", base_url, name), &name, &sha256)
+                .await?;
+
+            let entry = BundleEntry {
+                version,
+                file: name,
+                sha256,
+            };
+            let dir = work.join(entry.dir());
+            self.fs.copy_file(&archive, &dir.join(&entry.file))?;
+            shasums.write_to(self.fs, &dir)?;
+            index.push(release);
+            entries.push(entry);
+        }
+
+        let manifest = BundleManifest {
+            format: BUNDLE_FORMAT,
+            target: target.clone(),
+            versions: entries,
+        };
+        self.fs
+            .write_file(&work.join(BUNDLE_INDEX_FILE), &serde_json::to_vec(&index)?)?;
+        self.fs.write_file(
+            &work.join(BUNDLE_MANIFEST_FILE),
+            &serde_json::to_vec_pretty(&manifest)?,
+        )?;
+        Ok(manifest)
+    }
+
+    async fn install_from(&self, bundle: &Path, work: &Path) -> Result<Vec<String>, NveError> {
+        self.bundler.unpack(bundle, work).await?;
+        let manifest_path = work.join(BUNDLE_MANIFEST_FILE);
+        if !self.fs.exists(&manifest_path) {
+            return Err(NveError::InvalidBundle(format!(
+                "falta {BUNDLE_MANIFEST_FILE}"
+            )));
+        }
+        let manifest: BundleManifest = serde_json::from_slice(&self.fs.read_file(&manifest_path)?)
+            .map_err(|e| NveError::InvalidBundle(e.to_string()))?;
+        if manifest.format != BUNDLE_FORMAT {
+            return Err(NveError::InvalidBundle(format!(
+                "formato {} no soportado",
+                manifest.format
+            )));
+        }
+        let host = self.plat.target();
+        if manifest.target != host {
+            return Err(NveError::BundleTargetMismatch {
+                bundle: manifest.target.to_string(),
+                host: host.to_string(),
+            });
+        }
+
+        let offline = OfflineInstallService {
+            fs: self.fs,
+            plat: self.plat,
+            arch: self.arch,
+            layout: self.layout,
+            lock_timeout: self.lock_timeout,
+        };
+        let mut installed = Vec::new();
+        for entry in &manifest.versions {
+            // El hash de `bundle.json` no está firmado: manda el de `SHASUMS256.txt`.
+            let dir = work.join(entry.dir());
+            let text =
+                Shasums::read_from(self.fs, &dir)?.verified_text(self.verifier, &entry.dir())?;
+            let expected = find_checksum(&String::from_utf8_lossy(&text), &entry.file)
+                .ok_or_else(|| NveError::ChecksumNotFound(entry.file.clone()))?;
+            if expected != entry.sha256 {
+                return Err(NveError::InvalidBundle(format!(
+                    "el hash de {} no coincide con su SHASUMS256.txt",
+                    entry.file
+                )));
+            }
+            let archive = dir.join(&entry.file);
+            verify_checksum(self.fs.open_read(&archive)?, &expected, &entry.file)?;
+            installed.push(offline.install(LocalSource::Archive(&archive)).await?);
+        }
+        self.seed_index(&work.join(BUNDLE_INDEX_FILE), host.distribution())?;
+        Ok(installed)
+    }
+
+    /// Sin red no hay índice: se deja el del bundle como caché caducada (solo si no hay
+    /// otra) para que los alias LTS se puedan resolver con `nve use`.
+    fn seed_index(&self, index: &Path, dist: Distribution) -> Result<(), NveError> {
+        if !self.fs.exists(index) {
+            return Ok(());
+        }
+        let releases: Vec<NodeRelease> = serde_json::from_slice(&self.fs.read_file(index)?)
+            .map_err(|e| NveError::InvalidBundle(e.to_string()))?;
+        for channel in [Channel::Release, Channel::Rc, Channel::Nightly] {
+            let path = self.layout.index_cache_path(channel, dist);
+            let of_channel: Vec<&NodeRelease> = releases
+                .iter()
+                .filter(|r| Version::parse(r.number()).is_ok_and(|v| channel.accepts(&v)))
+                .collect();
+            if of_channel.is_empty() || self.fs.exists(&path) {
+                continue;
+            }
+            let meta = IndexCacheMeta {
+                url: Some(self.mirror.index_url(channel, dist)),
+                ..Default::default()
+            };
+            self.fs
+                .write_file(&path, &serde_json::to_vec(&of_channel)?)?;
+            self.fs
+                .write_file(&meta_path(&path), &serde_json::to_vec(&meta)?)?;
+        }
+        Ok(())
+    }
+
+    /// Directorio de trabajo en staging, protegido por su lock como cualquier otra entrada
+    /// para que `clean_staging` de otro proceso no lo borre a medias.
+    async fn work_dir(&self) -> Result<(LockGuard, PathBuf), NveError> {
+        let name = format!("bundle-{}", std::process::id());
+        let lock = acquire_lock(
+            self.fs,
+            &self.layout.version_lock_path(&name),
+            self.lock_timeout,
+        )
+        .await?;
+        let work = self.layout.staging_dir().join(name);
+        self.fs.remove_dir_all(&work)?;
+        self.fs.create_dir_all(&work)?;
+        Ok((lock, work))
+    }
+}
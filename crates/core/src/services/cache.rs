@@ -0,0 +1,81 @@
+use std::time::{Duration, SystemTime};
+
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+use crate::state::layout::NveLayout;
+
+/// Archivo verificado guardado en `cache/downloads`.
+#[derive(Debug, Clone)]
+pub struct CachedArchive {
+    pub file: String,
+    pub sha256: String,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+/// Resultado de `clean`/`prune`: archivos borrados y bytes liberados.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CacheRemoval {
+    pub removed: usize,
+    pub bytes: u64,
+}
+
+pub struct CacheService<'a, F> {
+    pub fs: &'a F,
+    pub layout: &'a NveLayout,
+}
+
+impl<'a, F: FileSystem> CacheService<'a, F> {
+    /// Archivos en caché, del más reciente al más antiguo.
+    pub fn list(&self) -> Result<Vec<CachedArchive>, NveError> {
+        let mut out: Vec<CachedArchive> = self
+            .fs
+            .list_files(&self.layout.downloads_dir())?
+            .into_iter()
+            .filter_map(|entry| {
+                let (sha256, file) = entry.name.split_once('-')?;
+                if sha256.len() != 64 || !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+                Some(CachedArchive {
+                    file: file.to_string(),
+                    sha256: sha256.to_string(),
+                    size: entry.size,
+                    modified: entry.modified,
+                })
+            })
+            .collect();
+        out.sort_by_key(|a| std::cmp::Reverse(a.modified));
+        Ok(out)
+    }
+
+    pub fn clean(&self) -> Result<CacheRemoval, NveError> {
+        self.remove_where(|_| true)
+    }
+
+    /// Borra los archivos que llevan más de `older_than` sin actualizarse.
+    pub fn prune(&self, older_than: Duration) -> Result<CacheRemoval, NveError> {
+        let now = SystemTime::now();
+        self.remove_where(|a| {
+            now.duration_since(a.modified)
+                .is_ok_and(|age| age > older_than)
+        })
+    }
+
+    fn remove_where(
+        &self,
+        pred: impl Fn(&CachedArchive) -> bool,
+    ) -> Result<CacheRemoval, NveError> {
+        let mut removal = CacheRemoval::default();
+        for archive in self.list()?.into_iter().filter(|a| pred(a)) {
+            self.fs.remove_file(
+                &self
+                    .layout
+                    .cached_archive_path(&archive.file, &archive.sha256),
+            )?;
+            removal.removed += 1;
+            removal.bytes += archive.size;
+        }
+        Ok(removal)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+use crate::ports::platform::Platform;
+use crate::ports::reporter::Reporter;
+use crate::state::layout::NveLayout;
+
+/// Versión actual. `nve use` la registra en `~/.nve/current`, pero manda lo que informa
+/// el `node` del directorio `nodejs`, que es el que está en el PATH.
+pub struct CurrentService<'a, F, P> {
+    pub fs: &'a F,
+    pub plat: &'a P,
+    pub layout: &'a NveLayout,
+    pub reporter: &'a dyn Reporter,
+}
+
+impl<'a, F, P> CurrentService<'a, F, P>
+where
+    F: FileSystem,
+    P: Platform,
+{
+    /// `None` si no hay ninguna versión activa.
+    pub async fn get(&self) -> Result<Option<String>, NveError> {
+        let recorded = self.recorded();
+        match self.plat.current_version(&self.layout.current_dir()).await {
+            Ok(Some(found)) => {
+                if let Some(recorded) = recorded.filter(|r| *r != found) {
+                    self.reporter.warn(&format!(
+                        "La versión registrada como actual ({recorded}) no coincide con la de \
+                         node --version ({found}); se toma {found}"
+                    ));
+                }
+                Ok(Some(found))
+            }
+            Ok(None) => Ok(None),
+            // El binario no arranca (p. ej. x64 sin Rosetta): solo queda lo registrado.
+            Err(_) => Ok(recorded),
+        }
+    }
+
+    pub async fn is_current(&self, version: &str) -> Result<bool, NveError> {
+        Ok(self.get().await?.as_deref() == Some(version))
+    }
+
+    /// Activa una versión de `versions/`. El llamante debe tener el lock global.
+    pub async fn set(&self, version: &str) -> Result<(), NveError> {
+        self.plat
+            .set_current(
+                &self.layout.version_dir(version),
+                &self.layout.current_dir(),
+            )
+            .await?;
+        self.fs.write_file(
+            &self.layout.current_version_path(),
+            format!("{version}\n").as_bytes(),
+        )
+    }
+
+    /// Deja sin versión activa. El llamante debe tener el lock global.
+    pub fn clear(&self) -> Result<(), NveError> {
+        let cur = self.layout.current_dir();
+        if self.fs.exists(&cur) {
+            self.fs.remove_dir_all(&cur)?;
+            self.fs.create_dir_all(&cur)?;
+        }
+        let state = self.layout.current_version_path();
+        if self.fs.exists(&state) {
+            self.fs.remove_file(&state)?;
+        }
+        Ok(())
+    }
+
+    fn recorded(&self) -> Option<String> {
+        let raw = self
+            .fs
+            .read_file(&self.layout.current_version_path())
+            .ok()?;
+        let version = String::from_utf8_lossy(&raw).trim().to_string();
+        (!version.is_empty()).then_some(version)
+    }
+}
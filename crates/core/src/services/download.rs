@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use crate::domain::checksum::verify_checksum;
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+use crate::ports::http::{partial_meta_path, HttpClient};
+use crate::ports::reporter::Reporter;
+use crate::state::layout::NveLayout;
+
+/// Descarga archivos de Node a la caché de descargas verificando su SHA-256.
+pub(crate) struct ArchiveFetcher<'a, H, F> {
+    pub http: &'a H,
+    pub fs: &'a F,
+    pub layout: &'a NveLayout,
+    pub reporter: &'a dyn Reporter,
+}
+
+impl<'a, H: HttpClient, F: FileSystem> ArchiveFetcher<'a, H, F> {
+    /// Devuelve el archivo verificado en `cache/downloads`, descargándolo solo si no está.
+    pub async fn fetch(&self, url: &str, name: &str, expected: &str) -> Result<PathBuf, NveError> {
+        let cached = self.layout.cached_archive_path(name, expected);
+        if self.fs.exists(&cached) {
+            // Una copia dañada en disco se descarta y se vuelve a descargar.
+            match verify_checksum(self.fs.open_read(&cached)?, expected, name) {
+                Ok(()) => return Ok(cached),
+                Err(_) => self.fs.remove_file(&cached)?,
+            }
+        }
+
+        // Si la red falla, la descarga parcial se queda en caché para reanudarla.
+        let partial = self.layout.partial_download_path(name);
+        self.fs
+            .create_dir_all(partial.parent().unwrap_or(&partial))?;
+        let on_progress = |done, total| self.reporter.download_progress(name, done, total);
+        let downloaded = self.http.download(url, &partial, &on_progress).await;
+        self.reporter.download_finished(name);
+        downloaded?;
+
+        let verified = verify_checksum(self.fs.open_read(&partial)?, expected, name);
+        let _ = self.fs.remove_file(&partial_meta_path(&partial));
+        if let Err(e) = verified {
+            let _ = self.fs.remove_file(&partial);
+            return Err(e);
+        }
+        self.fs.create_dir_all(&self.layout.downloads_dir())?;
+        self.fs.rename(&partial, &cached)?;
+        Ok(cached)
+    }
+}
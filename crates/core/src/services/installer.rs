@@ -1,13 +1,24 @@
+use crate::domain::artifact::Target;
+use crate::domain::checksum::find_checksum;
+use crate::domain::mirror::Mirror;
 use crate::domain::version::ParsedVersion;
 use crate::error::NveError;
 use crate::ports::archive::Archive;
 use crate::ports::fs::FileSystem;
 use crate::ports::http::HttpClient;
 use crate::ports::platform::Platform;
+use crate::ports::reporter::Reporter;
+use crate::ports::signature::SignatureVerifier;
+use crate::services::download::ArchiveFetcher;
+use crate::services::resolver::unix_now;
+use crate::services::shasums::fetch_shasums;
 use crate::services::ResolveService;
+use crate::state::install_meta::InstallMeta;
 use crate::state::layout::NveLayout;
+use crate::state::lock::acquire_lock;
+use std::time::Duration;
 
-use crate::constants::NODEJS_API_BASE;
+const MIN_GLIBC_NODE18: (u64, u64) = (2, 28);
 
 pub struct InstallService<'a, H, F, P, A> {
     pub http: &'a H,
@@ -15,6 +26,18 @@ pub struct InstallService<'a, H, F, P, A> {
     pub plat: &'a P,
     pub arch: &'a A,
     pub layout: &'a NveLayout,
+    /// Si está presente, se verifica la firma de `SHASUMS256.txt` antes de usar sus hashes.
+    pub verifier: Option<&'a dyn SignatureVerifier>,
+    /// Tiempo máximo de espera si otro proceso está instalando la misma versión.
+    pub lock_timeout: Duration,
+    pub reporter: &'a dyn Reporter,
+    pub index_ttl: Duration,
+    pub mirror: &'a Mirror,
+    /// Plataforma distinta de la del host. Si el host no puede ejecutarla se instala en
+    /// `targets/<os>-<arch>/`; si puede (x64 con Rosetta), en `versions/` como cualquier otra.
+    pub target: Option<&'a Target>,
+    /// Si la versión no tiene build para el host, instala el de la plataforma emulada.
+    pub emulation_fallback: bool,
 }
 impl<'a, H, F, P, A> InstallService<'a, H, F, P, A>
 where
@@ -24,20 +47,322 @@ where
     A: Archive,
 {
     pub async fn install(&self, spec: &ParsedVersion) -> Result<String, NveError> {
-        let exact = ResolveService { http: self.http }.resolve(spec).await?;
-        let version_dir = self.layout.version_dir(&exact);
+        let requested = self.target.cloned().unwrap_or_else(|| self.plat.target());
+        let (exact, target) = match self.resolve_for(spec, &requested).await {
+            Ok(exact) => (exact, requested),
+            Err(e @ NveError::NoBuildForTarget { .. }) => {
+                let emulated = self.emulation_fallback && self.target.is_none();
+                let Some(fallback) = emulated.then(|| self.plat.emulated_target()).flatten() else {
+                    return Err(e);
+                };
+                let exact = self.resolve_for(spec, &fallback).await?;
+                self.reporter.warn(&format!(
+                    "No hay build de Node {exact} para {requested}; se instala el de {fallback}, \
+                     que se ejecuta emulado"
+                ));
+                (exact, fallback)
+            }
+            Err(e) => return Err(e),
+        };
+        if self.target.is_none() {
+            self.warn_old_glibc(&exact);
+        }
+        let version_dir = if self.plat.can_run(&target) {
+            self.layout.version_dir(&exact)
+        } else {
+            self.layout.target_versions_dir(&target).join(&exact)
+        };
+        let _lock = acquire_lock(
+            self.fs,
+            &self.layout.version_lock_path(&exact),
+            self.lock_timeout,
+        )
+        .await?;
         if self.fs.exists(&version_dir) {
+            // En un Mac con Apple Silicon los builds arm64 y x64 comparten `versions/<v>`.
+            // Sin metadatos es una instalación anterior a ellos, que siempre era la del host.
+            let installed = InstallMeta::read(self.fs, &version_dir)
+                .map_or_else(|| self.plat.target(), |meta| meta.target);
+            if installed != target {
+                return Err(NveError::InstalledForOtherTarget {
+                    version: exact,
+                    installed: installed.to_string(),
+                    requested: target.to_string(),
+                });
+            }
             return Ok(exact);
         }
 
-        let url = {
-            let name = self.plat.archive_name(&exact);
-            format!("{}/v{}/{}", NODEJS_API_BASE, &exact, name)
+        let name = target.archive_name(&exact);
+        let base_url = format!(
+            "{}/v{}",
+            self.mirror.base_url(spec.channel, target.distribution()),
+            &exact
+        );
+
+        let shasums = fetch_shasums(self.http, &base_url, self.verifier).await?;
+        let expected = find_checksum(&String::from_utf8_lossy(&shasums), &name)
+            .ok_or_else(|| NveError::ChecksumNotFound(name.clone()))?;
+
+        let staging = self.layout.staging_dir().join(&exact);
+        self.fs.remove_dir_all(&staging)?;
+        self.clean_staging()?;
+        self.fs.create_dir_all(&staging)?;
+
+        let fetcher = ArchiveFetcher {
+            http: self.http,
+            fs: self.fs,
+            layout: self.layout,
+            reporter: self.reporter,
+        };
+        let result = match fetcher
+            .fetch(&format!("{}/{}", base_url, name), &name, &expected)
+            .await
+        {
+            Ok(archive) => match self.arch.extract(&archive, &staging, &exact).await {
+                Ok(()) => InstallMeta {
+                    target,
+                    installed_at: unix_now(),
+                }
+                .write(self.fs, &staging),
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
         };
+        if let Err(e) = result {
+            let _ = self.fs.remove_dir_all(&staging);
+            return Err(e);
+        }
 
-        let data = self.http.get_bytes(&url).await?;
-        self.fs.create_dir_all(&version_dir)?;
-        self.arch.extract(&data, &version_dir, &exact).await?;
+        // Solo una extracción completa llega a `versions/`: el rename es atómico.
+        self.fs
+            .create_dir_all(version_dir.parent().unwrap_or(&version_dir))?;
+        self.fs.rename(&staging, &version_dir)?;
         Ok(exact)
     }
+
+    async fn resolve_for(&self, spec: &ParsedVersion, target: &Target) -> Result<String, NveError> {
+        let resolver = ResolveService {
+            http: self.http,
+            fs: self.fs,
+            layout: self.layout,
+            reporter: self.reporter,
+            index_ttl: self.index_ttl,
+            mirror: self.mirror,
+            target,
+        };
+        resolver.resolve(spec).await
+    }
+
+    /// Los builds oficiales de Node 18+ requieren glibc 2.28; con una más antigua
+    /// se instalan sin error pero `node` no arranca.
+    fn warn_old_glibc(&self, version: &str) {
+        let major = semver::Version::parse(version).map_or(0, |v| v.major);
+        match self.plat.glibc_version() {
+            Some(glibc) if major >= 18 && glibc < MIN_GLIBC_NODE18 => {
+                self.reporter.warn(&format!(
+                    "Node {version} requiere glibc {}.{} o superior y el sistema tiene la {}.{}; \
+                     puede que no arranque",
+                    MIN_GLIBC_NODE18.0, MIN_GLIBC_NODE18.1, glibc.0, glibc.1
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    /// Elimina restos de instalaciones interrumpidas (Ctrl-C, errores de extracción...).
+    /// Se omiten las versiones que otro proceso está instalando en este momento.
+    pub fn clean_staging(&self) -> Result<(), NveError> {
+        clean_staging(self.fs, self.layout)
+    }
+}
+
+pub(crate) fn clean_staging<F: FileSystem>(fs: &F, layout: &NveLayout) -> Result<(), NveError> {
+    let staging = layout.staging_dir();
+    for name in fs.read_dir_names(&staging)? {
+        if let Some(_lock) = fs.try_lock(&layout.version_lock_path(&name))? {
+            fs.remove_dir_all(&staging.join(&name))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::checksum::sha256_hex;
+    use crate::ports::http::{CacheValidators, Revalidated};
+    use crate::test_support::DiskFs;
+    use std::collections::HashMap;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    const DIST: &str = "https://mirror.test/dist";
+    const ARCHIVE: &str = "node-v20.11.1-linux-x64.tar.xz";
+
+    /// Sirve cada URL desde memoria; el resto responde como un 404.
+    struct FakeHttp(HashMap<String, Vec<u8>>);
+
+    impl FakeHttp {
+        fn body(&self, url: &str) -> Result<Vec<u8>, NveError> {
+            self.0
+                .get(url)
+                .cloned()
+                .ok_or_else(|| NveError::artifact_unavailable(url))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for FakeHttp {
+        async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, NveError> {
+            self.body(url)
+        }
+
+        async fn get_json<T: serde::de::DeserializeOwned + Send>(
+            &self,
+            url: &str,
+        ) -> Result<T, NveError> {
+            Ok(serde_json::from_slice(&self.body(url)?)?)
+        }
+
+        async fn download(
+            &self,
+            url: &str,
+            dest: &Path,
+            _on_progress: &(dyn Fn(u64, Option<u64>) + Send + Sync),
+        ) -> Result<(), NveError> {
+            Ok(fs::write(dest, self.body(url)?)?)
+        }
+
+        async fn get_revalidated(
+            &self,
+            url: &str,
+            _validators: &CacheValidators,
+        ) -> Result<Revalidated, NveError> {
+            Ok(Revalidated::Modified {
+                body: self.body(url)?,
+                validators: CacheValidators::default(),
+            })
+        }
+    }
+
+    struct LinuxX64;
+
+    #[async_trait::async_trait]
+    impl Platform for LinuxX64 {
+        fn os_arch(&self) -> (String, String) {
+            ("linux".into(), "x64".into())
+        }
+        fn archive_name(&self, version: &str) -> String {
+            self.target().archive_name(version)
+        }
+        async fn set_current(
+            &self,
+            _version_dir: &Path,
+            _current_dir: &Path,
+        ) -> Result<(), NveError> {
+            Ok(())
+        }
+        async fn current_version(&self, _current_dir: &Path) -> Result<Option<String>, NveError> {
+            Ok(None)
+        }
+        fn node_binary(&self, install_dir: &Path) -> PathBuf {
+            install_dir.join("bin").join("node")
+        }
+        async fn node_version(&self, _install_dir: &Path) -> Result<String, NveError> {
+            Ok("20.11.1".into())
+        }
+    }
+
+    /// "Extrae" creando el binario de Node vacío.
+    struct FakeArchive;
+
+    #[async_trait::async_trait]
+    impl Archive for FakeArchive {
+        async fn extract(
+            &self,
+            _archive: &Path,
+            target_dir: &Path,
+            _version: &str,
+        ) -> Result<(), NveError> {
+            DiskFs.write_file(&target_dir.join("bin").join("node"), b"")
+        }
+    }
+
+    struct Silent;
+
+    impl Reporter for Silent {
+        fn warn(&self, _message: &str) {}
+    }
+
+    fn mirror(shasums: &str, archive: &[u8]) -> FakeHttp {
+        let index =
+            r#"[{"version":"v20.11.1","date":"2024-02-14","files":["linux-x64"],"lts":"Iron"}]"#;
+        FakeHttp(HashMap::from([
+            (format!("{DIST}/index.json"), index.as_bytes().to_vec()),
+            (
+                format!("{DIST}/v20.11.1/SHASUMS256.txt"),
+                shasums.as_bytes().to_vec(),
+            ),
+            (format!("{DIST}/v20.11.1/{ARCHIVE}"), archive.to_vec()),
+        ]))
+    }
+
+    async fn install(http: &FakeHttp, layout: &NveLayout) -> Result<String, NveError> {
+        let mirror = Mirror {
+            dist: DIST.into(),
+            ..Mirror::default()
+        };
+        InstallService {
+            http,
+            fs: &DiskFs,
+            plat: &LinuxX64,
+            arch: &FakeArchive,
+            layout,
+            verifier: None,
+            lock_timeout: Duration::ZERO,
+            reporter: &Silent,
+            index_ttl: Duration::ZERO,
+            mirror: &mirror,
+            target: None,
+            emulation_fallback: false,
+        }
+        .install(&ParsedVersion::parse("20").unwrap())
+        .await
+    }
+
+    #[tokio::test]
+    async fn installs_only_archives_matching_shasums() {
+        let home = tempfile::tempdir().unwrap();
+        let layout = NveLayout {
+            base: home.path().to_path_buf(),
+        };
+        let archive = b"node-v20.11.1";
+        let good = sha256_hex(archive);
+
+        let tampered = mirror(&format!("{good}  {ARCHIVE}\n"), b"otro contenido");
+        match install(&tampered, &layout).await {
+            Err(NveError::ChecksumMismatch { file, expected, .. }) => {
+                assert_eq!(file, ARCHIVE);
+                assert_eq!(expected, good);
+            }
+            other => panic!("se esperaba ChecksumMismatch: {other:?}"),
+        }
+        assert!(!layout.version_dir("20.11.1").exists());
+        assert!(!layout.cached_archive_path(ARCHIVE, &good).exists());
+
+        let unlisted = mirror(&format!("{good}  {ARCHIVE}.sig\n"), archive);
+        assert!(matches!(
+            install(&unlisted, &layout).await,
+            Err(NveError::ChecksumNotFound(name)) if name == ARCHIVE
+        ));
+
+        let valid = mirror(&format!("{good}  {ARCHIVE}\n"), archive);
+        assert_eq!(install(&valid, &layout).await.unwrap(), "20.11.1");
+        assert!(layout
+            .version_dir("20.11.1")
+            .join("bin")
+            .join("node")
+            .exists());
+    }
 }
@@ -1,5 +1,24 @@
+pub use bundle::*;
+mod bundle;
+
+pub use cache::*;
+mod cache;
+
+pub use current::*;
+mod current;
+
+mod download;
+
 pub use installer::*;
 mod installer;
 
+pub use offline::*;
+mod offline;
+
+pub use project::*;
+mod project;
+
 pub use resolver::*;
 mod resolver;
+
+mod shasums;
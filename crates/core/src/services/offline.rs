@@ -0,0 +1,138 @@
+use std::path::Path;
+use std::time::Duration;
+
+use crate::constants::SHASUMS_FILE;
+use crate::domain::artifact::version_from_archive_name;
+use crate::domain::checksum::{find_checksum, verify_checksum};
+use crate::error::NveError;
+use crate::ports::archive::Archive;
+use crate::ports::fs::FileSystem;
+use crate::ports::platform::Platform;
+use crate::services::installer::clean_staging;
+use crate::services::resolver::unix_now;
+use crate::state::install_meta::InstallMeta;
+use crate::state::layout::NveLayout;
+use crate::state::lock::acquire_lock;
+
+/// Instalación sin red a partir de un archivo o un directorio ya extraído.
+pub struct OfflineInstallService<'a, F, P, A> {
+    pub fs: &'a F,
+    pub plat: &'a P,
+    pub arch: &'a A,
+    pub layout: &'a NveLayout,
+    pub lock_timeout: Duration,
+}
+
+impl<'a, F, P, A> OfflineInstallService<'a, F, P, A>
+where
+    F: FileSystem,
+    P: Platform,
+    A: Archive,
+{
+    pub async fn install(&self, source: LocalSource<'_>) -> Result<String, NveError> {
+        match source {
+            LocalSource::Archive(archive) => self.install_file(archive).await,
+            LocalSource::Dir(dir) => self.install_dir(dir).await,
+        }
+    }
+
+    /// Instala un archivo oficial (`node-v20.11.1-linux-x64.tar.xz`). La versión sale del
+    /// nombre; si hay un `SHASUMS256.txt` al lado, también se verifica el checksum.
+    async fn install_file(&self, archive: &Path) -> Result<String, NveError> {
+        let name = archive
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| NveError::UnrecognizedArchive(archive.display().to_string()))?;
+        let version = version_from_archive_name(name)
+            .ok_or_else(|| NveError::UnrecognizedArchive(name.to_string()))?;
+
+        let shasums = archive.with_file_name(SHASUMS_FILE);
+        if self.fs.exists(&shasums) {
+            let shasums = String::from_utf8_lossy(&self.fs.read_file(&shasums)?).into_owned();
+            let expected = find_checksum(&shasums, name)
+                .ok_or_else(|| NveError::ChecksumNotFound(name.to_string()))?;
+            verify_checksum(self.fs.open_read(archive)?, &expected, name)?;
+        }
+
+        self.register(&version, name, LocalSource::Archive(archive))
+            .await?;
+        Ok(version)
+    }
+
+    /// Instala un árbol ya extraído; la versión se obtiene de su `node --version`.
+    async fn install_dir(&self, dir: &Path) -> Result<String, NveError> {
+        if !self.fs.exists(&self.plat.node_binary(dir)) {
+            return Err(NveError::invalid_layout(dir));
+        }
+        let version = self.plat.node_version(dir).await?;
+        self.register(&version, &dir.display().to_string(), LocalSource::Dir(dir))
+            .await?;
+        Ok(version)
+    }
+
+    /// Prepara `version` en staging, comprueba que su `node --version` coincide y la mueve
+    /// a `versions/`. No hace nada si ya está instalada.
+    async fn register(
+        &self,
+        version: &str,
+        origin: &str,
+        source: LocalSource<'_>,
+    ) -> Result<(), NveError> {
+        let version_dir = self.layout.version_dir(version);
+        let _lock = acquire_lock(
+            self.fs,
+            &self.layout.version_lock_path(version),
+            self.lock_timeout,
+        )
+        .await?;
+        if self.fs.exists(&version_dir) {
+            return Ok(());
+        }
+
+        let staging = self.layout.staging_dir().join(version);
+        self.fs.remove_dir_all(&staging)?;
+        clean_staging(self.fs, self.layout)?;
+        self.fs.create_dir_all(&staging)?;
+
+        if let Err(e) = self.populate(version, origin, source, &staging).await {
+            let _ = self.fs.remove_dir_all(&staging);
+            return Err(e);
+        }
+        self.fs.create_dir_all(&self.layout.versions_dir())?;
+        self.fs.rename(&staging, &version_dir)?;
+        Ok(())
+    }
+
+    async fn populate(
+        &self,
+        version: &str,
+        origin: &str,
+        source: LocalSource<'_>,
+        staging: &Path,
+    ) -> Result<(), NveError> {
+        match source {
+            LocalSource::Archive(archive) => self.arch.extract(archive, staging, version).await?,
+            LocalSource::Dir(dir) => self.fs.copy_dir_recursive(dir, staging)?,
+        }
+        let found = self.plat.node_version(staging).await?;
+        if found != version {
+            return Err(NveError::LocalVersionMismatch {
+                origin: origin.to_string(),
+                expected: version.to_string(),
+                found,
+            });
+        }
+        InstallMeta {
+            target: self.plat.target(),
+            installed_at: unix_now(),
+        }
+        .write(self.fs, staging)
+    }
+}
+
+pub enum LocalSource<'p> {
+    /// Archivo oficial (`node-v20.11.1-linux-x64.tar.xz`, `node-v20.11.1-win-x64.zip`).
+    Archive(&'p Path),
+    /// Árbol ya extraído, con `bin/node` (o `node.exe`) dentro.
+    Dir(&'p Path),
+}
@@ -0,0 +1,78 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::constants::{NODE_VERSION_FILE, NVMRC_FILE, PACKAGE_JSON_FILE};
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+
+/// Spec de versión declarado por un proyecto y el fichero del que sale.
+#[derive(Debug, Clone)]
+pub struct ProjectSpec {
+    pub spec: String,
+    pub source: PathBuf,
+}
+
+pub struct ProjectVersionService<'a, F: FileSystem> {
+    pub fs: &'a F,
+}
+
+impl<'a, F: FileSystem> ProjectVersionService<'a, F> {
+    /// Sube desde `start` buscando, en cada directorio, `.nvmrc`, `.node-version` y
+    /// `package.json` (`engines.node`, `volta.node`, `devEngines.runtime`), por ese orden.
+    pub fn find(&self, start: &Path) -> Result<Option<ProjectSpec>, NveError> {
+        for dir in start.ancestors() {
+            for file in [NVMRC_FILE, NODE_VERSION_FILE] {
+                let path = dir.join(file);
+                if !self.fs.exists(&path) {
+                    continue;
+                }
+                if let Some(spec) = first_line(&String::from_utf8_lossy(&self.fs.read_file(&path)?))
+                {
+                    return Ok(Some(ProjectSpec { spec, source: path }));
+                }
+            }
+
+            let path = dir.join(PACKAGE_JSON_FILE);
+            if self.fs.exists(&path) {
+                // Un package.json malformado no debe impedir seguir buscando hacia arriba.
+                let json: Option<Value> = serde_json::from_slice(&self.fs.read_file(&path)?).ok();
+                if let Some(spec) = json.as_ref().and_then(package_json_spec) {
+                    return Ok(Some(ProjectSpec { spec, source: path }));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Primera línea útil de `.nvmrc`/`.node-version` (ignora vacías y comentarios `#`).
+fn first_line(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .find(|l| !l.is_empty())
+        .map(str::to_string)
+}
+
+fn package_json_spec(json: &Value) -> Option<String> {
+    let engines = json.pointer("/engines/node").and_then(Value::as_str);
+    let volta = json.pointer("/volta/node").and_then(Value::as_str);
+    let dev_engines = json.pointer("/devEngines/runtime").and_then(|rt| {
+        // `runtime` puede ser un objeto o una lista de alternativas.
+        let runtimes = rt
+            .as_array()
+            .map_or_else(|| vec![rt], |a| a.iter().collect());
+        runtimes
+            .into_iter()
+            .find(|r| r.get("name").and_then(Value::as_str) == Some("node"))
+            .and_then(|r| r.get("version"))
+            .and_then(Value::as_str)
+    });
+    engines
+        .or(volta)
+        .or(dev_engines)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
@@ -1,21 +1,167 @@
-use crate::constants::NODEJS_API_INDEX;
-use crate::domain::release::NodeRelease;
-use crate::domain::version::{matches_semver, ParsedVersion};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::domain::artifact::Target;
+use crate::domain::channel::Channel;
+use crate::domain::mirror::Mirror;
+use crate::domain::release::{matching_releases, nearest_with_build, NodeRelease};
+use crate::domain::version::ParsedVersion;
 use crate::error::NveError;
-use crate::ports::http::HttpClient;
+use crate::ports::fs::FileSystem;
+use crate::ports::http::{HttpClient, Revalidated};
+use crate::ports::reporter::Reporter;
+use crate::state::index_cache::IndexCacheMeta;
+use crate::state::layout::NveLayout;
 
-pub struct ResolveService<'a, H: HttpClient> {
+pub struct ResolveService<'a, H: HttpClient, F: FileSystem> {
     pub http: &'a H,
+    pub fs: &'a F,
+    pub layout: &'a NveLayout,
+    pub reporter: &'a dyn Reporter,
+    /// Antigüedad máxima del índice cacheado antes de revalidarlo contra el servidor.
+    pub index_ttl: Duration,
+    pub mirror: &'a Mirror,
+    /// Plataforma para la que se resuelve; musl, `armv6l`... se consultan en unofficial-builds.
+    pub target: &'a Target,
 }
 
-impl<'a, H: HttpClient> ResolveService<'a, H> {
+impl<'a, H: HttpClient, F: FileSystem> ResolveService<'a, H, F> {
     pub async fn resolve(&self, spec: &ParsedVersion) -> Result<String, NveError> {
-        let releases: Vec<NodeRelease> = self.http.get_json(NODEJS_API_INDEX).await?;
+        self.matching_versions(spec)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| NveError::VersionNotFound(spec.full_version.clone()))
+    }
+
+    /// Todas las versiones remotas que encajan en `spec`, de más nueva a más antigua.
+    pub async fn matching_versions(&self, spec: &ParsedVersion) -> Result<Vec<String>, NveError> {
+        Ok(self
+            .available_releases(spec)
+            .await?
+            .into_iter()
+            .map(|r| r.number().to_string())
+            .collect())
+    }
 
-        releases
+    /// Versiones del índice que encajan en `spec`, tengan o no build para el destino. Es lo
+    /// que necesitan las búsquedas entre versiones ya instaladas (`use`, `remove`, `.nvmrc`):
+    /// una instalación hecha para otra arquitectura no debe fallar con `NoBuildForTarget`.
+    pub async fn known_versions(&self, spec: &ParsedVersion) -> Result<Vec<String>, NveError> {
+        let releases = self.releases(spec.channel).await?;
+        Ok(matching_releases(&releases, spec)
+            .into_iter()
+            .map(|r| r.number().to_string())
+            .collect())
+    }
+
+    /// Releases que encajan en `spec` y publican build para el destino (según `files` del
+    /// índice). Si ninguna lo tiene se sugiere la versión más cercana que sí lo tenga.
+    pub async fn available_releases(
+        &self,
+        spec: &ParsedVersion,
+    ) -> Result<Vec<NodeRelease>, NveError> {
+        let releases = self.releases(spec.channel).await?;
+        let matching = matching_releases(&releases, spec);
+        let key = self.target.artifact_key();
+        let available: Vec<NodeRelease> = matching
             .iter()
-            .filter_map(|r| r.version.strip_prefix('v').map(str::to_string))
-            .find(|v| matches_semver(v, spec))
-            .ok_or_else(|| NveError::VersionNotFound(spec.full_version.clone()))
+            .filter(|r| r.has_build(&key))
+            .map(|r| (*r).clone())
+            .collect();
+        match matching.first() {
+            Some(newest) if available.is_empty() => Err(NveError::NoBuildForTarget {
+                version: newest.number().to_string(),
+                target: self.target.to_string(),
+                nearest: nearest_with_build(&releases, newest.number(), &key)
+                    .map(|r| r.number().to_string()),
+            }),
+            _ => Ok(available),
+        }
+    }
+
+    /// Índice de releases del canal, servido desde `~/.nve/cache` mientras no supere el TTL.
+    /// Si el servidor no responde se usa la copia cacheada, avisando de que puede estar obsoleta.
+    pub async fn releases(&self, channel: Channel) -> Result<Vec<NodeRelease>, NveError> {
+        let dist = self.target.distribution();
+        let url = self.mirror.index_url(channel, dist);
+        let path = self.layout.index_cache_path(channel, dist);
+        let meta_path = meta_path(&path);
+        let meta: IndexCacheMeta = if self.fs.exists(&path) && self.fs.exists(&meta_path) {
+            serde_json::from_slice(&self.fs.read_file(&meta_path)?).unwrap_or_default()
+        } else {
+            IndexCacheMeta::default()
+        };
+        // Una copia descargada de otro mirror no sirve ni como respaldo.
+        let cached = meta.url.as_deref() == Some(url.as_str());
+
+        let now = unix_now();
+        if cached && now.saturating_sub(meta.fetched_at) < self.index_ttl.as_secs() {
+            return Ok(serde_json::from_slice(&self.fs.read_file(&path)?)?);
+        }
+
+        let validators = if cached {
+            meta.validators()
+        } else {
+            Default::default()
+        };
+        match self.http.get_revalidated(&url, &validators).await {
+            Ok(Revalidated::NotModified) => {
+                let meta = IndexCacheMeta {
+                    fetched_at: now,
+                    ..meta
+                };
+                self.write_atomic(&meta_path, &serde_json::to_vec(&meta)?)?;
+                Ok(serde_json::from_slice(&self.fs.read_file(&path)?)?)
+            }
+            Ok(Revalidated::Modified { body, validators }) => {
+                let releases = serde_json::from_slice(&body)?;
+                let meta = IndexCacheMeta {
+                    url: Some(url),
+                    etag: validators.etag,
+                    last_modified: validators.last_modified,
+                    fetched_at: now,
+                };
+                self.write_atomic(&path, &body)?;
+                self.write_atomic(&meta_path, &serde_json::to_vec(&meta)?)?;
+                Ok(releases)
+            }
+            Err(e) if cached => {
+                // `fetched_at == 0`: índice sembrado por `nve bundle import`, nunca descargado.
+                let origin = match meta.fetched_at {
+                    0 => "importada de un bundle".to_string(),
+                    at => format!("de hace {} min", now.saturating_sub(at) / 60),
+                };
+                self.reporter.warn(&format!(
+                    "No se pudo actualizar el índice de versiones ({e}); usando la copia en caché \
+                     {origin}, puede estar desactualizada"
+                ));
+                Ok(serde_json::from_slice(&self.fs.read_file(&path)?)?)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Otro proceso puede estar leyendo la caché: se escribe aparte y se renombra, para que
+    /// nunca vea un JSON a medias.
+    fn write_atomic(&self, path: &Path, data: &[u8]) -> Result<(), NveError> {
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(format!(".{}.tmp", std::process::id()));
+        let tmp = PathBuf::from(tmp);
+        self.fs.write_file(&tmp, data)?;
+        self.fs.rename(&tmp, path)
     }
 }
+
+pub(crate) fn meta_path(index_path: &Path) -> PathBuf {
+    let mut name = index_path.as_os_str().to_owned();
+    name.push(".meta");
+    PathBuf::from(name)
+}
+
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use crate::constants::{SHASUMS_ASC_FILE, SHASUMS_FILE, SHASUMS_SIG_FILE};
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+use crate::ports::http::HttpClient;
+use crate::ports::signature::SignatureVerifier;
+
+/// `SHASUMS256.txt` de una release con la firma que la acompaña, si se publicó.
+pub(crate) struct Shasums {
+    pub text: Vec<u8>,
+    pub signature: Option<ShasumsSignature>,
+}
+
+pub(crate) enum ShasumsSignature {
+    /// `SHASUMS256.txt.sig`, firma separada de `text`.
+    Detached(Vec<u8>),
+    /// `SHASUMS256.txt.asc`, que lleva dentro el propio texto firmado.
+    Clearsigned(Vec<u8>),
+}
+
+impl Shasums {
+    /// Descarga `SHASUMS256.txt` de `base_url` junto con la firma separada (`.sig`) o, si la
+    /// release no la publica, la firma en claro (`.asc`). Sin ninguna de las dos no es error:
+    /// lo decide `verified_text` según haya o no verificador.
+    pub async fn fetch<H: HttpClient>(http: &H, base_url: &str) -> Result<Self, NveError> {
+        let text = http
+            .get_bytes(&format!("{base_url}/{SHASUMS_FILE}"))
+            .await?;
+        let signature = match http
+            .get_bytes(&format!("{base_url}/{SHASUMS_SIG_FILE}"))
+            .await
+        {
+            Ok(sig) => Some(ShasumsSignature::Detached(sig)),
+            Err(NveError::ArtifactUnavailable(_)) => {
+                match http
+                    .get_bytes(&format!("{base_url}/{SHASUMS_ASC_FILE}"))
+                    .await
+                {
+                    Ok(asc) => Some(ShasumsSignature::Clearsigned(asc)),
+                    Err(NveError::ArtifactUnavailable(_)) => None,
+                    Err(e) => return Err(e),
+                }
+            }
+            Err(e) => return Err(e),
+        };
+        Ok(Self { text, signature })
+    }
+
+    /// Lee lo que dejó `write_to` en `dir` (p. ej. dentro de un bundle).
+    pub fn read_from<F: FileSystem>(fs: &F, dir: &Path) -> Result<Self, NveError> {
+        let text = fs.read_file(&dir.join(SHASUMS_FILE))?;
+        let sig = dir.join(SHASUMS_SIG_FILE);
+        let asc = dir.join(SHASUMS_ASC_FILE);
+        let signature = if fs.exists(&sig) {
+            Some(ShasumsSignature::Detached(fs.read_file(&sig)?))
+        } else if fs.exists(&asc) {
+            Some(ShasumsSignature::Clearsigned(fs.read_file(&asc)?))
+        } else {
+            None
+        };
+        Ok(Self { text, signature })
+    }
+
+    pub fn write_to<F: FileSystem>(&self, fs: &F, dir: &Path) -> Result<(), NveError> {
+        fs.write_file(&dir.join(SHASUMS_FILE), &self.text)?;
+        match &self.signature {
+            Some(ShasumsSignature::Detached(sig)) => {
+                fs.write_file(&dir.join(SHASUMS_SIG_FILE), sig)
+            }
+            Some(ShasumsSignature::Clearsigned(asc)) => {
+                fs.write_file(&dir.join(SHASUMS_ASC_FILE), asc)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Texto del que tomar los hashes. Con `verifier` se exige una firma válida y, si es en
+    /// claro, se usa el texto firmado en lugar del `SHASUMS256.txt` suelto. `origin` solo
+    /// sirve para el mensaje de error si no hay firma.
+    pub fn verified_text(
+        &self,
+        verifier: Option<&dyn SignatureVerifier>,
+        origin: &str,
+    ) -> Result<Vec<u8>, NveError> {
+        match (verifier, &self.signature) {
+            (None, _) => Ok(self.text.clone()),
+            (Some(_), None) => Err(NveError::SignatureNotFound(origin.to_string())),
+            (Some(verifier), Some(ShasumsSignature::Detached(sig))) => {
+                verifier.verify(&self.text, sig)?;
+                Ok(self.text.clone())
+            }
+            (Some(verifier), Some(ShasumsSignature::Clearsigned(asc))) => {
+                verifier.verify_clearsigned(asc)
+            }
+        }
+    }
+}
+
+/// Hashes de la release en `base_url`. Sin `verifier` solo se descarga `SHASUMS256.txt`.
+pub(crate) async fn fetch_shasums<H: HttpClient>(
+    http: &H,
+    base_url: &str,
+    verifier: Option<&dyn SignatureVerifier>,
+) -> Result<Vec<u8>, NveError> {
+    match verifier {
+        None => http.get_bytes(&format!("{base_url}/{SHASUMS_FILE}")).await,
+        Some(_) => Shasums::fetch(http, base_url)
+            .await?
+            .verified_text(verifier, base_url),
+    }
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::ports::http::CacheValidators;
+
+/// Metadatos guardados junto a cada índice cacheado (`index.json.meta`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexCacheMeta {
+    /// URL de la que se descargó; la caché se descarta si cambia el mirror.
+    #[serde(default)]
+    pub url: Option<String>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Segundos UNIX de la última descarga o revalidación correcta.
+    pub fetched_at: u64,
+}
+
+impl IndexCacheMeta {
+    pub fn validators(&self) -> CacheValidators {
+        CacheValidators {
+            etag: self.etag.clone(),
+            last_modified: self.last_modified.clone(),
+        }
+    }
+}
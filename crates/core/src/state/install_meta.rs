@@ -0,0 +1,32 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::INSTALL_META_FILE;
+use crate::domain::artifact::Target;
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+
+/// Metadatos de una instalación (`versions/<v>/.nve-install.json`). El build puede no ser
+/// el del host: en un Mac con Apple Silicon, uno x64 que se ejecuta con Rosetta.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallMeta {
+    pub target: Target,
+    /// Segundos UNIX de la instalación.
+    pub installed_at: u64,
+}
+
+impl InstallMeta {
+    /// `None` si falta o no se puede leer (instalaciones anteriores a los metadatos).
+    pub fn read<F: FileSystem>(fs: &F, install_dir: &Path) -> Option<Self> {
+        let raw = fs.read_file(&install_dir.join(INSTALL_META_FILE)).ok()?;
+        serde_json::from_slice(&raw).ok()
+    }
+
+    pub fn write<F: FileSystem>(&self, fs: &F, install_dir: &Path) -> Result<(), NveError> {
+        fs.write_file(
+            &install_dir.join(INSTALL_META_FILE),
+            &serde_json::to_vec_pretty(self)?,
+        )
+    }
+}
@@ -1,6 +1,12 @@
 use std::path::PathBuf;
 
-use crate::constants::{NODEJS_DIR, VERSION_DIR};
+use crate::constants::{
+    BUNDLED_KEYRING_FILE, CACHE_DIR, CONFIG_FILE, CURRENT_VERSION_FILE, DOWNLOADS_DIR,
+    KEYRING_FILE, KEYS_DIR, LOCKS_DIR, LOCK_FILE, NODEJS_DIR, PARTIAL_DIR, STAGING_DIR,
+    TARGETS_DIR, VERSION_DIR,
+};
+use crate::domain::artifact::{Distribution, Target};
+use crate::domain::channel::Channel;
 
 pub struct NveLayout {
     pub base: PathBuf,
@@ -13,7 +19,74 @@ impl NveLayout {
     pub fn current_dir(&self) -> PathBuf {
         self.base.join(NODEJS_DIR)
     }
+    /// Versión activada por última vez con `nve use`.
+    pub fn current_version_path(&self) -> PathBuf {
+        self.base.join(CURRENT_VERSION_FILE)
+    }
     pub fn version_dir(&self, v: &str) -> PathBuf {
         self.versions_dir().join(v)
     }
+    pub fn targets_dir(&self) -> PathBuf {
+        self.base.join(TARGETS_DIR)
+    }
+    /// Instalaciones para otra plataforma (`--os`/`--arch`), fuera de `versions/` para que
+    /// nunca pasen a ser la versión actual.
+    pub fn target_versions_dir(&self, target: &Target) -> PathBuf {
+        self.targets_dir().join(target.to_string())
+    }
+    /// Directorio temporal donde se extraen las instalaciones antes de moverlas a `versions/`.
+    pub fn staging_dir(&self) -> PathBuf {
+        self.base.join(STAGING_DIR)
+    }
+    /// Lock global: protege el enlace/directorio `nodejs` actual.
+    pub fn lock_path(&self) -> PathBuf {
+        self.base.join(LOCK_FILE)
+    }
+    /// Lock por versión: protege `versions/<v>` y su directorio de staging.
+    pub fn version_lock_path(&self, v: &str) -> PathBuf {
+        self.base.join(LOCKS_DIR).join(format!("{v}.lock"))
+    }
+    pub fn cache_dir(&self) -> PathBuf {
+        self.base.join(CACHE_DIR)
+    }
+    /// `cache/index.json` para releases estables, `cache/index-unofficial.json` para las de
+    /// unofficial-builds y `cache/index-<canal>.json` para el resto.
+    pub fn index_cache_path(&self, channel: Channel, dist: Distribution) -> PathBuf {
+        match (channel, dist) {
+            (Channel::Release, Distribution::Official) => self.cache_dir().join("index.json"),
+            (Channel::Release, Distribution::Unofficial) => {
+                self.cache_dir().join("index-unofficial.json")
+            }
+            (other, _) => self
+                .cache_dir()
+                .join(format!("index-{}.json", other.name())),
+        }
+    }
+    /// Descargas a medias, conservadas para reanudarlas en el siguiente intento.
+    pub fn partial_download_path(&self, file: &str) -> PathBuf {
+        self.cache_dir().join(PARTIAL_DIR).join(file)
+    }
+    /// Archivos ya verificados, reutilizables en reinstalaciones.
+    pub fn downloads_dir(&self) -> PathBuf {
+        self.cache_dir().join(DOWNLOADS_DIR)
+    }
+    /// `cache/downloads/<sha256>-<fichero>`: el hash evita reutilizar un archivo distinto
+    /// publicado con el mismo nombre.
+    pub fn cached_archive_path(&self, file: &str, sha256: &str) -> PathBuf {
+        self.downloads_dir().join(format!("{sha256}-{file}"))
+    }
+    pub fn config_path(&self) -> PathBuf {
+        self.base.join(CONFIG_FILE)
+    }
+    pub fn keys_dir(&self) -> PathBuf {
+        self.base.join(KEYS_DIR)
+    }
+    /// Keyring importado con `nve keys import`; tiene prioridad sobre el incrustado.
+    pub fn keyring_path(&self) -> PathBuf {
+        self.keys_dir().join(KEYRING_FILE)
+    }
+    /// Copia en disco del keyring incrustado en el binario.
+    pub fn bundled_keyring_path(&self) -> PathBuf {
+        self.keys_dir().join(BUNDLED_KEYRING_FILE)
+    }
 }
@@ -0,0 +1,32 @@
+use std::fs::File;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::error::NveError;
+use crate::ports::fs::FileSystem;
+
+const RETRY_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Lock advisory entre procesos; se libera al hacer drop.
+pub struct LockGuard {
+    _file: File,
+}
+
+/// Espera hasta `timeout` a que el lock quede libre.
+/// Con `timeout` cero falla de inmediato si otro proceso lo tiene.
+pub async fn acquire_lock<F: FileSystem>(
+    fs: &F,
+    path: &Path,
+    timeout: Duration,
+) -> Result<LockGuard, NveError> {
+    let start = Instant::now();
+    loop {
+        if let Some(file) = fs.try_lock(path)? {
+            return Ok(LockGuard { _file: file });
+        }
+        if start.elapsed() >= timeout {
+            return Err(NveError::ConcurrencyConflict);
+        }
+        tokio::time::sleep(RETRY_INTERVAL).await;
+    }
+}
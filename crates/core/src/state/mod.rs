@@ -1 +1,4 @@
+pub mod index_cache;
+pub mod install_meta;
 pub mod layout;
+pub mod lock;
@@ -0,0 +1,65 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Read;
+use std::path::Path;
+
+use crate::error::NveError;
+use crate::ports::fs::{FileEntry, FileSystem};
+
+/// Disco real (dentro de un directorio temporal); los locks nunca están ocupados.
+pub(crate) struct DiskFs;
+
+impl FileSystem for DiskFs {
+    fn create_dir_all(&self, path: &Path) -> Result<(), NveError> {
+        Ok(fs::create_dir_all(path)?)
+    }
+    fn remove_dir_all(&self, path: &Path) -> Result<(), NveError> {
+        match fs::remove_dir_all(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+    fn read_dir_names(&self, path: &Path) -> Result<Vec<String>, NveError> {
+        let Ok(entries) = fs::read_dir(path) else {
+            return Ok(Vec::new());
+        };
+        entries
+            .map(|e| Ok(e?.file_name().to_string_lossy().into_owned()))
+            .collect()
+    }
+    fn list_files(&self, _path: &Path) -> Result<Vec<FileEntry>, NveError> {
+        Ok(Vec::new())
+    }
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+    fn copy_dir_recursive(&self, _from: &Path, _to: &Path) -> Result<(), NveError> {
+        unimplemented!()
+    }
+    fn rename(&self, from: &Path, to: &Path) -> Result<(), NveError> {
+        Ok(fs::rename(from, to)?)
+    }
+    fn copy_file(&self, from: &Path, to: &Path) -> Result<(), NveError> {
+        fs::copy(from, to)?;
+        Ok(())
+    }
+    fn read_file(&self, path: &Path) -> Result<Vec<u8>, NveError> {
+        Ok(fs::read(path)?)
+    }
+    fn open_read(&self, path: &Path) -> Result<Box<dyn Read + Send>, NveError> {
+        Ok(Box::new(File::open(path)?))
+    }
+    fn remove_file(&self, path: &Path) -> Result<(), NveError> {
+        Ok(fs::remove_file(path)?)
+    }
+    fn write_file(&self, path: &Path, data: &[u8]) -> Result<(), NveError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(fs::write(path, data)?)
+    }
+    fn try_lock(&self, path: &Path) -> Result<Option<File>, NveError> {
+        self.create_dir_all(path.parent().unwrap_or(path))?;
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Some(file))
+    }
+}
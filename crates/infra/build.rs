@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+use std::{env, fs};
+
+/// Keyring de claves de release de Node que se incrusta en el binario. Se actualiza con
+/// `scripts/update-release-keys.sh`; sin él el binario se compila igual, pero
+/// `--verify-signature` solo funciona tras `nve keys import`.
+const KEYRING: &str = "keys/nodejs-release-keys.kbx";
+
+fn main() {
+    println!("cargo:rerun-if-changed=keys");
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR"));
+    let data = fs::read(KEYRING).unwrap_or_else(|_| {
+        println!("cargo:warning={KEYRING} no existe: el binario no llevará claves de release");
+        Vec::new()
+    });
+    fs::write(out.join("nodejs-release-keys.kbx"), data).expect("no se pudo escribir el keyring");
+}
@@ -1,11 +1,45 @@
-#[cfg(unix)]
 pub mod tar_xz;
-
-#[cfg(windows)]
 pub mod zip;
 
-#[cfg(unix)]
 pub use tar_xz::TarXzArchive;
-
-#[cfg(windows)]
 pub use zip::ZipArchive;
+
+use async_trait::async_trait;
+use nve_core::error::NveError;
+use nve_core::ports::archive::Archive;
+use std::path::Path;
+
+/// Elige el extractor por la extensión y no por el host: con `--os win` desde Linux (o
+/// `--os linux` desde Windows) el archivo es del formato de la otra plataforma.
+pub struct NodeArchive {
+    tar_xz: TarXzArchive,
+    zip: ZipArchive,
+}
+
+impl NodeArchive {
+    pub fn new() -> Result<Self, NveError> {
+        Ok(Self {
+            tar_xz: TarXzArchive::new()?,
+            zip: ZipArchive::new()?,
+        })
+    }
+}
+
+#[async_trait]
+impl Archive for NodeArchive {
+    async fn extract(
+        &self,
+        archive: &Path,
+        target_dir: &Path,
+        version: &str,
+    ) -> Result<(), NveError> {
+        let is_zip = archive
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"));
+        if is_zip {
+            self.zip.extract(archive, target_dir, version).await
+        } else {
+            self.tar_xz.extract(archive, target_dir, version).await
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use nve_core::error::NveError;
 use nve_core::ports::archive::Archive;
-use std::fs::{self};
-use std::io::Cursor;
+use std::fs::{self, File};
+use std::io::BufReader;
 use std::path::Path;
 use tar::Archive as TarArchive;
 use xz2::read::XzDecoder;
@@ -19,21 +19,23 @@ impl TarXzArchive {
 impl Archive for TarXzArchive {
     async fn extract(
         &self,
-        data: &[u8],
+        archive: &Path,
         target_dir: &Path,
         _version: &str,
     ) -> Result<(), NveError> {
-        use tempfile::tempdir;
+        use tempfile::tempdir_in;
 
-        let tmp = tempdir()?;
-        TarArchive::new(XzDecoder::new(Cursor::new(data))).unpack(tmp.path())?;
+        // Extrae dentro del propio destino: los `rename` no cruzan de sistema de ficheros y el
+        // temporal queda bajo el lock de la versión, así que `clean_staging` no lo borra.
+        fs::create_dir_all(target_dir)?;
+        let tmp = tempdir_in(target_dir)?;
+        TarArchive::new(XzDecoder::new(BufReader::new(File::open(archive)?))).unpack(tmp.path())?;
 
         let root = std::fs::read_dir(tmp.path())?
             .next()
             .ok_or_else(|| NveError::ExtractError("empty archive".into()))??
             .path();
 
-        fs::create_dir_all(target_dir)?;
         for entry in fs::read_dir(root)? {
             let e = entry?;
             fs::rename(e.path(), target_dir.join(e.file_name()))?;
@@ -1,10 +1,10 @@
+use ::zip::ZipArchive as ZipReader;
 use async_trait::async_trait;
 use nve_core::error::NveError;
 use nve_core::ports::archive::Archive;
 use std::fs::{self, File};
-use std::io::{Cursor, Read, Write};
-use std::path::{Path, PathBuf};
-use zip::ZipArchive;
+use std::io::BufReader;
+use std::path::Path;
 
 pub struct ZipArchive;
 
@@ -18,21 +18,27 @@ impl ZipArchive {
 impl Archive for ZipArchive {
     async fn extract(
         &self,
-        data: &[u8],
+        archive: &Path,
         target_dir: &Path,
         _version: &str,
     ) -> Result<(), NveError> {
+        use tempfile::tempdir_in;
+
+        // Igual que en tar.xz: se extrae en un temporal dentro del destino y se descarta la
+        // carpeta `node-vX-win-ARCH/` para que `node.exe` quede en la raíz de la instalación.
         fs::create_dir_all(target_dir)?;
-        let reader = Cursor::new(data);
-        let mut zip = ZipArchive::new(reader).map_err(|e| NveError::extract_err(e.to_string()))?;
+        let tmp = tempdir_in(target_dir)?;
+
+        let reader = BufReader::new(File::open(archive)?);
+        let mut zip = ZipReader::new(reader).map_err(|e| NveError::extract_err(e.to_string()))?;
 
         for i in 0..zip.len() {
             let mut file = zip
                 .by_index(i)
                 .map_err(|e| NveError::extract_err(e.to_string()))?;
-            let outpath = target_dir.join(file.mangled_name());
+            let outpath = tmp.path().join(file.mangled_name());
 
-            if (&*file.name()).ends_with('/') {
+            if file.is_dir() {
                 fs::create_dir_all(&outpath)?;
             } else {
                 if let Some(p) = outpath.parent() {
@@ -42,6 +48,16 @@ impl Archive for ZipArchive {
                 std::io::copy(&mut file, &mut outfile)?;
             }
         }
+
+        let root = fs::read_dir(tmp.path())?
+            .next()
+            .ok_or_else(|| NveError::ExtractError("empty archive".into()))??
+            .path();
+
+        for entry in fs::read_dir(root)? {
+            let e = entry?;
+            fs::rename(e.path(), target_dir.join(e.file_name()))?;
+        }
         Ok(())
     }
 }
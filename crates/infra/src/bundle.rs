@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use nve_core::error::NveError;
+use nve_core::ports::bundle::Bundler;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use tar::{Archive as TarArchive, Builder};
+
+/// Bundles en formato tar, legibles en cualquier sistema.
+pub struct TarBundler;
+
+impl TarBundler {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TarBundler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Bundler for TarBundler {
+    async fn pack(&self, dir: &Path, dest: &Path) -> Result<(), NveError> {
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut builder = Builder::new(BufWriter::new(File::create(dest)?));
+        builder.append_dir_all(".", dir)?;
+        builder
+            .into_inner()?
+            .into_inner()
+            .map_err(|e| e.into_error())?;
+        Ok(())
+    }
+
+    async fn unpack(&self, bundle: &Path, target_dir: &Path) -> Result<(), NveError> {
+        fs::create_dir_all(target_dir)?;
+        TarArchive::new(BufReader::new(File::open(bundle)?)).unpack(target_dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use indicatif::{ProgressBar, ProgressStyle};
+use nve_core::ports::reporter::Reporter;
+use std::io::IsTerminal;
+use std::sync::Mutex;
+
+/// Escribe los avisos en stderr para no mezclarlos con la salida de los comandos.
+/// La barra de progreso solo se muestra si stdout es una terminal.
+pub struct ConsoleReporter {
+    show_progress: bool,
+    bar: Mutex<Option<ProgressBar>>,
+}
+
+impl ConsoleReporter {
+    pub fn new() -> Self {
+        Self {
+            show_progress: std::io::stdout().is_terminal(),
+            bar: Mutex::new(None),
+        }
+    }
+}
+
+impl Default for ConsoleReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reporter for ConsoleReporter {
+    fn warn(&self, message: &str) {
+        eprintln!("warning: {message}");
+    }
+
+    fn download_progress(&self, file: &str, downloaded: u64, total: Option<u64>) {
+        if !self.show_progress {
+            return;
+        }
+        let mut bar = self.bar.lock().unwrap_or_else(|e| e.into_inner());
+        let bar = bar.get_or_insert_with(|| new_bar(file, total));
+        bar.set_position(downloaded);
+    }
+
+    fn download_finished(&self, _file: &str) {
+        if let Some(bar) = self.bar.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            bar.finish_and_clear();
+        }
+    }
+}
+
+fn new_bar(file: &str, total: Option<u64>) -> ProgressBar {
+    let (bar, template) = match total {
+        Some(len) => (
+            ProgressBar::new(len),
+            "{msg} [{bar:30}] {bytes}/{total_bytes} {bytes_per_sec} ETA {eta}",
+        ),
+        None => (
+            ProgressBar::new_spinner(),
+            "{msg} {spinner} {bytes} {bytes_per_sec}",
+        ),
+    };
+    if let Ok(style) = ProgressStyle::with_template(template) {
+        bar.set_style(style.progress_chars("=> "));
+    }
+    bar.set_message(file.to_string());
+    bar
+}
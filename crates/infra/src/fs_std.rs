@@ -1,6 +1,7 @@
 use nve_core::error::NveError;
-use nve_core::ports::fs::FileSystem;
-use std::fs;
+use nve_core::ports::fs::{FileEntry, FileSystem};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{BufReader, ErrorKind, Read};
 use std::path::{Path, PathBuf};
 
 pub struct StdFs;
@@ -47,6 +48,27 @@ impl FileSystem for StdFs {
         Ok(out)
     }
 
+    fn list_files(&self, path: &Path) -> Result<Vec<FileEntry>, NveError> {
+        let mut out = Vec::new();
+        if !path.exists() {
+            return Ok(out);
+        }
+        for e in fs::read_dir(path)? {
+            let e = e?;
+            let meta = e.metadata()?;
+            if meta.is_file() {
+                if let Some(s) = e.file_name().to_str() {
+                    out.push(FileEntry {
+                        name: s.to_string(),
+                        size: meta.len(),
+                        modified: meta.modified()?,
+                    });
+                }
+            }
+        }
+        Ok(out)
+    }
+
     fn exists(&self, path: &Path) -> bool {
         path.exists()
     }
@@ -61,7 +83,14 @@ impl FileSystem for StdFs {
             let entry = entry.map_err(|e| NveError::Io(std::io::Error::other(e)))?;
             let rel: PathBuf = entry.path().strip_prefix(from).unwrap().into();
             let dest = to.join(&rel);
-            if entry.file_type().is_dir() {
+            if entry.file_type().is_symlink() {
+                // Se recrea el enlace tal cual: `bin/npm` -> `../lib/node_modules/...` debe
+                // seguir siendo relativo al árbol copiado.
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                copy_symlink(entry.path(), &dest)?;
+            } else if entry.file_type().is_dir() {
                 fs::create_dir_all(&dest)?;
             } else {
                 if let Some(parent) = dest.parent() {
@@ -72,4 +101,73 @@ impl FileSystem for StdFs {
         }
         Ok(())
     }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<(), NveError> {
+        Ok(fs::rename(from, to)?)
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) -> Result<(), NveError> {
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(from, to)?;
+        Ok(())
+    }
+
+    fn read_file(&self, path: &Path) -> Result<Vec<u8>, NveError> {
+        Ok(fs::read(path)?)
+    }
+
+    fn open_read(&self, path: &Path) -> Result<Box<dyn Read + Send>, NveError> {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<(), NveError> {
+        // No falla si no existe
+        match fs::remove_file(path) {
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            other => Ok(other?),
+        }
+    }
+
+    fn write_file(&self, path: &Path, data: &[u8]) -> Result<(), NveError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(fs::write(path, data)?)
+    }
+
+    fn try_lock(&self, path: &Path) -> Result<Option<File>, NveError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path)?;
+        match file.try_lock() {
+            Ok(()) => Ok(Some(file)),
+            Err(TryLockError::WouldBlock) => Ok(None),
+            Err(TryLockError::Error(e)) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn copy_symlink(link: &Path, dest: &Path) -> Result<(), NveError> {
+    std::os::unix::fs::symlink(fs::read_link(link)?, dest)?;
+    Ok(())
+}
+
+#[cfg(windows)]
+fn copy_symlink(link: &Path, dest: &Path) -> Result<(), NveError> {
+    let target = fs::read_link(link)?;
+    // Windows distingue enlaces a fichero y a directorio; se mira a qué apunta el original.
+    if fs::metadata(link).is_ok_and(|m| m.is_dir()) {
+        std::os::windows::fs::symlink_dir(target, dest)?;
+    } else {
+        std::os::windows::fs::symlink_file(target, dest)?;
+    }
+    Ok(())
 }
@@ -0,0 +1,86 @@
+use nve_core::constants::{SHASUMS_ASC_FILE, SHASUMS_FILE, SHASUMS_SIG_FILE};
+use nve_core::error::NveError;
+use nve_core::ports::signature::SignatureVerifier;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Verificador basado en `gpgv`, que solo confía en las claves del keyring indicado
+/// (nunca en el keyring personal del usuario).
+pub struct GpgvVerifier {
+    keyring: PathBuf,
+}
+
+/// Claves de release de Node incrustadas al compilar (vacío si no se incluyeron).
+const BUNDLED_KEYRING: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/nodejs-release-keys.kbx"));
+
+impl GpgvVerifier {
+    pub fn new(keyring: impl Into<PathBuf>) -> Result<Self, NveError> {
+        let keyring = keyring.into();
+        if !keyring.is_file() {
+            return Err(NveError::KeyringNotFound(keyring));
+        }
+        Ok(Self { keyring })
+    }
+
+    /// Usa el keyring importado con `nve keys import` si existe; si no, el incrustado en
+    /// el binario, que se vuelca a `bundled` (gpgv solo lee ficheros).
+    pub fn with_bundled(imported: &Path, bundled: &Path) -> Result<Self, NveError> {
+        if imported.is_file() {
+            return Self::new(imported);
+        }
+        if BUNDLED_KEYRING.is_empty() {
+            return Err(NveError::NoBundledKeyring);
+        }
+        if fs::read(bundled).ok().as_deref() != Some(BUNDLED_KEYRING) {
+            if let Some(dir) = bundled.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let tmp = bundled.with_extension("tmp");
+            fs::write(&tmp, BUNDLED_KEYRING)?;
+            fs::rename(&tmp, bundled)?;
+        }
+        Self::new(bundled)
+    }
+}
+
+impl GpgvVerifier {
+    /// Ejecuta `gpgv` con el keyring y devuelve su salida estándar.
+    fn gpgv(&self, args: &[&OsStr]) -> Result<Vec<u8>, NveError> {
+        let out = Command::new("gpgv")
+            .arg("--keyring")
+            .arg(&self.keyring)
+            .args(args)
+            .output()
+            .map_err(|_| NveError::PlatformUnsupported("gpgv no está disponible en el PATH"))?;
+
+        if out.status.success() {
+            Ok(out.stdout)
+        } else {
+            Err(NveError::SignatureInvalid(
+                String::from_utf8_lossy(&out.stderr).trim().to_string(),
+            ))
+        }
+    }
+}
+
+impl SignatureVerifier for GpgvVerifier {
+    fn verify(&self, data: &[u8], signature: &[u8]) -> Result<(), NveError> {
+        let tmp = tempfile::tempdir()?;
+        let data_path = tmp.path().join(SHASUMS_FILE);
+        let sig_path = tmp.path().join(SHASUMS_SIG_FILE);
+        fs::write(&data_path, data)?;
+        fs::write(&sig_path, signature)?;
+        self.gpgv(&[sig_path.as_os_str(), data_path.as_os_str()])
+            .map(drop)
+    }
+
+    fn verify_clearsigned(&self, signed: &[u8]) -> Result<Vec<u8>, NveError> {
+        let tmp = tempfile::tempdir()?;
+        let asc_path = tmp.path().join(SHASUMS_ASC_FILE);
+        fs::write(&asc_path, signed)?;
+        // Con `--output` gpgv escribe solo el texto cubierto por la firma.
+        self.gpgv(&["--output".as_ref(), "-".as_ref(), asc_path.as_os_str()])
+    }
+}
@@ -1,25 +1,375 @@
 use async_trait::async_trait;
+use nve_core::config::HttpConfig;
 use nve_core::error::NveError;
-use nve_core::ports::http::HttpClient;
+use nve_core::ports::http::{
+    partial_meta_path, CacheValidators, Credentials, HostCredentials, HttpClient, Revalidated,
+};
+use reqwest::header::{
+    ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, IF_RANGE, LAST_MODIFIED, RANGE, RETRY_AFTER,
+};
+use reqwest::{
+    Certificate, Client, Identity, NoProxy, Proxy, RequestBuilder, Response, StatusCode,
+};
 use serde::de::DeserializeOwned;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
 
-pub struct ReqwestHttp(pub reqwest::Client);
+pub struct ReqwestHttp {
+    client: Client,
+    retry: RetryPolicy,
+    credentials: Vec<HostCredentials>,
+}
+
+/// Reintentos con backoff exponencial para fallos transitorios (conexión, timeouts, 5xx...).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    pub statuses: Vec<StatusCode>,
+}
+
+impl ReqwestHttp {
+    pub fn new(config: &HttpConfig, credentials: Vec<HostCredentials>) -> Result<Self, NveError> {
+        Ok(Self {
+            client: build_client(config)?,
+            retry: RetryPolicy::from(config),
+            credentials,
+        })
+    }
+
+    /// Añade las credenciales del host de `url`, si las hay. reqwest marca la cabecera
+    /// `Authorization` como sensible y la descarta en redirecciones a otro host.
+    fn authorize(&self, url: &str, req: RequestBuilder) -> RequestBuilder {
+        let host = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_string));
+        let found = self
+            .credentials
+            .iter()
+            .find(|c| host.as_deref() == Some(c.host.as_str()));
+        match found.map(|c| &c.credentials) {
+            Some(Credentials::Bearer(token)) => req.bearer_auth(token),
+            Some(Credentials::Basic { user, password }) => req.basic_auth(user, password.as_ref()),
+            None => req,
+        }
+    }
+
+    /// Envía la petición reintentando los fallos transitorios. Un 404/403 que persiste
+    /// se traduce a `ArtifactUnavailable`; el resto de códigos los trata el llamante.
+    async fn send(
+        &self,
+        url: &str,
+        build: impl Fn(&Client) -> RequestBuilder,
+    ) -> Result<Response, NveError> {
+        let mut attempt = 0;
+        loop {
+            let result = self.authorize(url, build(&self.client)).send().await;
+            let retryable = match &result {
+                Ok(res) => self.retry.statuses.contains(&res.status()),
+                Err(e) => e.is_connect() || e.is_timeout() || e.is_request(),
+            };
+            if retryable && attempt < self.retry.retries {
+                let delay = result
+                    .as_ref()
+                    .ok()
+                    .and_then(retry_after)
+                    .map(|d| d.min(self.retry.max_delay))
+                    .unwrap_or_else(|| self.retry.delay(attempt));
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+            let res = result?;
+            if matches!(res.status(), StatusCode::NOT_FOUND | StatusCode::FORBIDDEN) {
+                return Err(NveError::artifact_unavailable(url));
+            }
+            return Ok(res);
+        }
+    }
+
+    /// GET completo: un corte mientras se lee el cuerpo también se reintenta.
+    async fn get_body(&self, url: &str) -> Result<Vec<u8>, NveError> {
+        let mut attempt = 0;
+        loop {
+            let res = self.send(url, |c| c.get(url)).await?.error_for_status()?;
+            match res.bytes().await {
+                Ok(body) => return Ok(body.to_vec()),
+                Err(e) => self.body_backoff(&mut attempt, e).await?,
+            }
+        }
+    }
+
+    /// Espera antes de repetir una petición cuyo cuerpo se cortó (reset, timeout de lectura).
+    /// Devuelve el error si no es transitorio o se agotaron los reintentos.
+    async fn body_backoff(&self, attempt: &mut u32, e: reqwest::Error) -> Result<(), NveError> {
+        let transient = e.is_body() || e.is_timeout() || e.is_decode();
+        if !transient || *attempt >= self.retry.retries {
+            return Err(e.into());
+        }
+        tokio::time::sleep(self.retry.delay(*attempt)).await;
+        *attempt += 1;
+        Ok(())
+    }
+}
 
 impl Default for ReqwestHttp {
     fn default() -> Self {
-        Self(reqwest::Client::new())
+        Self {
+            client: Client::new(),
+            retry: RetryPolicy::from(&HttpConfig::default()),
+            credentials: Vec::new(),
+        }
+    }
+}
+
+fn build_client(config: &HttpConfig) -> Result<Client, NveError> {
+    let mut builder = Client::builder()
+        .connect_timeout(Duration::from_secs(config.connect_timeout_secs))
+        .read_timeout(Duration::from_secs(config.read_timeout_secs));
+
+    if let Some(url) = &config.proxy {
+        // El error de reqwest incluye la URL, que puede llevar credenciales: no se propaga.
+        let mut proxy =
+            Proxy::all(url).map_err(|_| NveError::invalid_http_config("URL de proxy inválida"))?;
+        if let Some(user) = &config.proxy_user {
+            let password = config.proxy_password.as_ref().map_or("", |p| p.expose());
+            proxy = proxy.basic_auth(user, password);
+        }
+        let no_proxy = match &config.no_proxy {
+            Some(list) => NoProxy::from_string(list),
+            None => NoProxy::from_env(),
+        };
+        builder = builder.proxy(proxy.no_proxy(no_proxy));
+    }
+
+    for path in &config.ca_certs {
+        let pem = read_pem(path)?;
+        let certs = Certificate::from_pem_bundle(&pem).map_err(|e| pem_error(path, e))?;
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    if let Some(cert) = &config.client_cert {
+        let mut pem = read_pem(cert)?;
+        if let Some(key) = &config.client_key {
+            pem.push(b'\n');
+            pem.extend(read_pem(key)?);
+        }
+        let identity = Identity::from_pem(&pem).map_err(|e| pem_error(cert, e))?;
+        // `from_pem` solo crea identidades rustls; con el backend nativo (el de por defecto)
+        // `build()` fallaría con "incompatible TLS identity type".
+        builder = builder.use_rustls_tls().identity(identity);
+    }
+
+    Ok(builder.build()?)
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>, NveError> {
+    std::fs::read(path).map_err(|e| pem_error(path, e))
+}
+
+fn pem_error(path: &Path, e: impl std::fmt::Display) -> NveError {
+    NveError::invalid_http_config(format!("{}: {e}", path.display()))
+}
+
+impl RetryPolicy {
+    /// Espera antes del reintento `attempt` (empezando en 0): `base * 2^attempt`, con tope
+    /// en `max_delay` y, si hay jitter, un valor aleatorio entre la mitad y el total.
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay.as_millis() as u64 / 2;
+        Duration::from_millis(half + rand::random_range(0..=half))
+    }
+}
+
+impl From<&HttpConfig> for RetryPolicy {
+    fn from(config: &HttpConfig) -> Self {
+        Self {
+            retries: config.retries,
+            base_delay: Duration::from_millis(config.retry_delay_ms),
+            max_delay: Duration::from_millis(config.retry_max_delay_ms),
+            jitter: config.retry_jitter,
+            statuses: config
+                .retry_statuses
+                .iter()
+                .filter_map(|code| StatusCode::from_u16(*code).ok())
+                .collect(),
+        }
     }
 }
 
 #[async_trait]
 impl HttpClient for ReqwestHttp {
     async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, NveError> {
-        let res = self.0.get(url).send().await?.error_for_status()?;
-        Ok(res.bytes().await?.to_vec())
+        self.get_body(url).await
     }
 
     async fn get_json<T: DeserializeOwned + Send>(&self, url: &str) -> Result<T, NveError> {
-        let res = self.0.get(url).send().await?.error_for_status()?;
-        Ok(res.json().await?)
+        Ok(serde_json::from_slice(&self.get_body(url).await?)?)
+    }
+
+    async fn download(
+        &self,
+        url: &str,
+        dest: &Path,
+        on_progress: &(dyn Fn(u64, Option<u64>) + Send + Sync),
+    ) -> Result<(), NveError> {
+        let meta_path = partial_meta_path(dest);
+        let mut attempt = 0;
+        // Si el cuerpo se corta, la siguiente vuelta reanuda desde lo ya escrito.
+        loop {
+            let offset = std::fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+            // Solo se reanuda si hay un validador con el que comprobar que el recurso no ha
+            // cambiado.
+            let if_range = (offset > 0)
+                .then(|| std::fs::read(&meta_path).ok())
+                .flatten()
+                .and_then(|raw| serde_json::from_slice::<CacheValidators>(&raw).ok())
+                .and_then(|v| v.etag.or(v.last_modified));
+
+            let mut res = self
+                .send(url, |c| match &if_range {
+                    Some(validator) => c
+                        .get(url)
+                        .header(RANGE, format!("bytes={offset}-"))
+                        .header(IF_RANGE, validator),
+                    None => c.get(url),
+                })
+                .await?;
+            if res.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+                res = self.send(url, |c| c.get(url)).await?;
+            }
+            let mut res = res.error_for_status()?;
+
+            let resumed = if_range.is_some() && res.status() == StatusCode::PARTIAL_CONTENT;
+            let (file, mut done) = if resumed {
+                let file = std::fs::OpenOptions::new().append(true).open(dest)?;
+                (file, offset)
+            } else {
+                // Descarga nueva (o el servidor ignoró el Range): se guardan los validadores
+                // antes del cuerpo para poder reanudarla si se interrumpe.
+                let validators = response_validators(&res);
+                std::fs::write(&meta_path, serde_json::to_vec(&validators)?)?;
+                (std::fs::File::create(dest)?, 0)
+            };
+            let total = res.content_length().map(|len| len + done);
+            let mut file = std::io::BufWriter::new(file);
+            on_progress(done, total);
+            let interrupted = loop {
+                match res.chunk().await {
+                    Ok(Some(chunk)) => {
+                        file.write_all(&chunk)?;
+                        done += chunk.len() as u64;
+                        on_progress(done, total);
+                    }
+                    Ok(None) => break None,
+                    Err(e) => break Some(e),
+                }
+            };
+            file.flush()?;
+            match interrupted {
+                None => return Ok(()),
+                Some(e) => self.body_backoff(&mut attempt, e).await?,
+            }
+        }
+    }
+
+    async fn get_revalidated(
+        &self,
+        url: &str,
+        validators: &CacheValidators,
+    ) -> Result<Revalidated, NveError> {
+        let mut attempt = 0;
+        loop {
+            let res = self
+                .send(url, |c| {
+                    let mut req = c.get(url);
+                    if let Some(etag) = &validators.etag {
+                        req = req.header(IF_NONE_MATCH, etag);
+                    }
+                    if let Some(lm) = &validators.last_modified {
+                        req = req.header(IF_MODIFIED_SINCE, lm);
+                    }
+                    req
+                })
+                .await?;
+            if res.status() == StatusCode::NOT_MODIFIED {
+                return Ok(Revalidated::NotModified);
+            }
+            let res = res.error_for_status()?;
+            let validators = response_validators(&res);
+            match res.bytes().await {
+                Ok(body) => {
+                    return Ok(Revalidated::Modified {
+                        body: body.to_vec(),
+                        validators,
+                    })
+                }
+                Err(e) => self.body_backoff(&mut attempt, e).await?,
+            }
+        }
+    }
+}
+
+fn response_validators(res: &reqwest::Response) -> CacheValidators {
+    let header = |name| {
+        res.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    CacheValidators {
+        etag: header(ETAG),
+        last_modified: header(LAST_MODIFIED),
+    }
+}
+
+/// `Retry-After` en segundos (429/503); el formato de fecha HTTP se ignora.
+fn retry_after(res: &Response) -> Option<Duration> {
+    let secs = res.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    secs.trim().parse().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn testdata(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join(name)
+    }
+
+    #[test]
+    fn client_cert_builds_client() {
+        let separate = HttpConfig {
+            client_cert: Some(testdata("client.crt")),
+            client_key: Some(testdata("client.key")),
+            ..HttpConfig::default()
+        };
+        assert!(ReqwestHttp::new(&separate, Vec::new()).is_ok());
+
+        // Certificado y clave en el mismo fichero.
+        let dir = tempfile::tempdir().unwrap();
+        let combined = dir.path().join("client.pem");
+        let mut pem = std::fs::read(testdata("client.crt")).unwrap();
+        pem.extend(std::fs::read(testdata("client.key")).unwrap());
+        std::fs::write(&combined, pem).unwrap();
+        let config = HttpConfig {
+            client_cert: Some(combined),
+            ..HttpConfig::default()
+        };
+        assert!(ReqwestHttp::new(&config, Vec::new()).is_ok());
     }
 }
@@ -1,4 +1,7 @@
 pub mod archive;
+pub mod bundle;
+pub mod console;
 pub mod fs_std;
+pub mod gpgv;
 pub mod http_client;
 pub mod platform;
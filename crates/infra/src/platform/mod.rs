@@ -9,3 +9,32 @@ pub use unix::UnixPlatform;
 
 #[cfg(windows)]
 pub use windows::WindowsPlatform;
+
+use nve_core::error::NveError;
+use std::path::Path;
+use std::process::Command;
+
+/// `<node> --version` -> `20.11.1`. Un binario que no arranca (otra arquitectura,
+/// instalación incompleta...) se trata como una instalación inválida.
+pub(crate) fn run_node_version(node: &Path, install_dir: &Path) -> Result<String, NveError> {
+    let out = Command::new(node)
+        .arg("--version")
+        .output()
+        .map_err(|_| NveError::invalid_layout(install_dir))?;
+    if !out.status.success() {
+        return Err(NveError::invalid_layout(install_dir));
+    }
+    let version = String::from_utf8_lossy(&out.stdout);
+    Ok(version.trim().trim_start_matches('v').to_string())
+}
+
+/// Como `run_node_version`, pero un directorio sin `node` no tiene versión activa.
+pub(crate) fn current_node_version(
+    node: &Path,
+    current_dir: &Path,
+) -> Result<Option<String>, NveError> {
+    if !node.exists() {
+        return Ok(None);
+    }
+    run_node_version(node, current_dir).map(Some)
+}
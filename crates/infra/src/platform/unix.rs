@@ -1,42 +1,128 @@
 use async_trait::async_trait;
+use nve_core::domain::artifact::{Libc, Target};
 use nve_core::error::NveError;
 use nve_core::ports::platform::Platform;
 use std::fs;
 use std::os::unix::fs as unix_fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
-pub struct UnixPlatform;
+use super::{current_node_version, run_node_version};
+
+const OS: &str = if cfg!(target_os = "macos") {
+    "darwin"
+} else {
+    "linux"
+};
+
+/// Runtime de Rosetta 2; solo existe si el usuario lo ha instalado.
+const ROSETTA_RUNTIME: &str = "/Library/Apple/usr/libexec/oah/libRosettaRuntime";
+
+pub struct UnixPlatform {
+    arch: String,
+    libc: Libc,
+    glibc: Option<(u64, u64)>,
+}
 
 impl UnixPlatform {
     pub fn new() -> Result<Self, NveError> {
-        Ok(Self)
+        let glibc = detect_glibc();
+        let libc = if glibc.is_none() && has_musl_loader() {
+            Libc::Musl
+        } else {
+            Libc::Glibc
+        };
+        Ok(Self {
+            arch: detect_arch()?,
+            libc,
+            glibc,
+        })
     }
 }
 
+/// Arquitectura con los nombres de nodejs.org. Una desconocida es un error: descargar
+/// el build de otra arquitectura solo falla más tarde y de forma más confusa.
+fn detect_arch() -> Result<String, NveError> {
+    let arch = std::env::consts::ARCH;
+    let node_arch = match (OS, arch) {
+        (_, "x86_64") => "x64",
+        (_, "aarch64") => "arm64",
+        // El target de Rust no distingue ARMv6 de ARMv7; `armv8l` es un userland de
+        // 32 bits sobre un kernel de 64, que ejecuta los builds de ARMv7.
+        ("linux", "arm") => match uname_machine().as_deref() {
+            Some("armv6l") => "armv6l",
+            Some("armv7l" | "armv8l") => "armv7l",
+            _ => return Err(NveError::archive_name_build_failed(OS, arch)),
+        },
+        ("linux", "powerpc64") if cfg!(target_endian = "little") => "ppc64le",
+        ("linux", "s390x") => "s390x",
+        ("linux", "riscv64") => "riscv64",
+        ("linux", "loongarch64") => "loong64",
+        _ => return Err(NveError::archive_name_build_failed(OS, arch)),
+    };
+    Ok(node_arch.to_string())
+}
+
+fn uname_machine() -> Option<String> {
+    let out = Command::new("uname").arg("-m").output().ok()?;
+    out.status
+        .success()
+        .then(|| String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// `getconf GNU_LIBC_VERSION` -> `glibc 2.35`. Solo existe con glibc.
+fn detect_glibc() -> Option<(u64, u64)> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let out = Command::new("getconf")
+        .arg("GNU_LIBC_VERSION")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())?;
+    let text = String::from_utf8_lossy(&out.stdout);
+    let (major, minor) = text.trim().strip_prefix("glibc ")?.split_once('.')?;
+    let minor = minor.split(|c: char| !c.is_ascii_digit()).next()?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// musl instala su cargador como `/lib/ld-musl-<arch>.so.1`. Se comprueba después de
+/// glibc porque algunas distros glibc lo traen también (paquete `musl`).
+fn has_musl_loader() -> bool {
+    cfg!(target_os = "linux")
+        && fs::read_dir("/lib").is_ok_and(|entries| {
+            entries
+                .flatten()
+                .any(|e| e.file_name().to_string_lossy().starts_with("ld-musl-"))
+        })
+}
+
 #[async_trait]
 impl Platform for UnixPlatform {
     fn os_arch(&self) -> (String, String) {
-        // os: "linux" | "darwin", arch: "x64" | "arm64"
-        let os = if cfg!(target_os = "macos") {
-            "darwin"
-        } else {
-            "linux"
-        }
-        .to_string();
-        let arch = if cfg!(target_arch = "x86_64") {
-            "x64"
-        } else if cfg!(target_arch = "aarch64") {
-            "arm64"
-        } else {
-            "x64"
-        }
-        .to_string();
-        (os, arch)
+        // os: "linux" | "darwin", arch: "x64" | "arm64" | "armv7l" | "ppc64le" | "s390x"...
+        (OS.to_string(), self.arch.clone())
+    }
+
+    fn libc(&self) -> Libc {
+        self.libc
+    }
+
+    fn glibc_version(&self) -> Option<(u64, u64)> {
+        self.glibc
+    }
+
+    fn emulated_target(&self) -> Option<Target> {
+        let rosetta = OS == "darwin" && self.arch == "arm64" && Path::new(ROSETTA_RUNTIME).exists();
+        rosetta.then(|| Target {
+            os: OS.to_string(),
+            arch: "x64".to_string(),
+            libc: Libc::Glibc,
+        })
     }
 
     fn archive_name(&self, version: &str) -> String {
-        let (os, arch) = self.os_arch();
-        format!("node-v{}-{}-{}.tar.xz", version, os, arch)
+        self.target().archive_name(version)
     }
 
     async fn set_current(&self, version_dir: &Path, current_dir: &Path) -> Result<(), NveError> {
@@ -48,11 +134,15 @@ impl Platform for UnixPlatform {
         Ok(())
     }
 
-    async fn is_current(&self, version: &str, current_dir: &Path) -> Result<bool, NveError> {
-        if let Ok(link) = fs::read_link(current_dir) {
-            Ok(link.ends_with(version))
-        } else {
-            Ok(false)
-        }
+    async fn current_version(&self, current_dir: &Path) -> Result<Option<String>, NveError> {
+        current_node_version(&self.node_binary(current_dir), current_dir)
+    }
+
+    fn node_binary(&self, install_dir: &Path) -> PathBuf {
+        install_dir.join("bin").join("node")
+    }
+
+    async fn node_version(&self, install_dir: &Path) -> Result<String, NveError> {
+        run_node_version(&self.node_binary(install_dir), install_dir)
     }
 }
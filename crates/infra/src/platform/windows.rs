@@ -4,33 +4,33 @@ use nve_core::ports::platform::Platform;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-pub struct WindowsPlatform;
+use super::{current_node_version, run_node_version};
+
+pub struct WindowsPlatform {
+    arch: &'static str,
+}
 
 impl WindowsPlatform {
     pub fn new() -> Result<Self, NveError> {
-        Ok(Self)
+        let arch = match std::env::consts::ARCH {
+            "x86_64" => "x64",
+            "aarch64" => "arm64",
+            "x86" => "x86",
+            other => return Err(NveError::archive_name_build_failed("win", other)),
+        };
+        Ok(Self { arch })
     }
 }
 
 #[async_trait]
 impl Platform for WindowsPlatform {
     fn os_arch(&self) -> (String, String) {
-        // os: "win", arch: "x64" | "arm64"
-        let os = "win".to_string();
-        let arch = if cfg!(target_arch = "x86_64") {
-            "x64"
-        } else if cfg!(target_arch = "aarch64") {
-            "arm64"
-        } else {
-            "x64"
-        }
-        .to_string();
-        (os, arch)
+        // os: "win", arch: "x64" | "arm64" | "x86"
+        ("win".to_string(), self.arch.to_string())
     }
 
     fn archive_name(&self, version: &str) -> String {
-        let (_os, arch) = self.os_arch();
-        format!("node-v{}-win-{}.zip", version, arch)
+        self.target().archive_name(version)
     }
 
     async fn set_current(&self, version_dir: &Path, current_dir: &Path) -> Result<(), NveError> {
@@ -42,8 +42,16 @@ impl Platform for WindowsPlatform {
         Ok(())
     }
 
-    async fn is_current(&self, version: &str, current_dir: &Path) -> Result<bool, NveError> {
-        Ok(current_dir.join("README.md").exists() || current_dir.exists())
+    async fn current_version(&self, current_dir: &Path) -> Result<Option<String>, NveError> {
+        current_node_version(&self.node_binary(current_dir), current_dir)
+    }
+
+    fn node_binary(&self, install_dir: &Path) -> PathBuf {
+        install_dir.join("node.exe")
+    }
+
+    async fn node_version(&self, install_dir: &Path) -> Result<String, NveError> {
+        run_node_version(&self.node_binary(install_dir), install_dir)
     }
 }
 
@@ -11,6 +11,7 @@ readonly NVE_VERSION="${NVE_VERSION:-latest}"
 readonly NVE_HOME="${NVE_HOME:-$HOME/.nve}"
 readonly NVE_BIN_DIR="$NVE_HOME/bin"
 readonly NVE_CACHE_DIR="$NVE_HOME/cache"
+readonly NVE_KEYS_DIR="$NVE_HOME/keys"
 readonly REPO_URL="https://github.com/jgalaber/node-virtual-enviroment-cli"
 readonly BINARY_NAME="nve"
 
@@ -161,6 +162,7 @@ create_directories() {
     mkdir -p "$NVE_BIN_DIR"
     mkdir -p "$NVE_CACHE_DIR"
     mkdir -p "$NVE_HOME/versions"
+    mkdir -p "$NVE_KEYS_DIR"
     
     log_success "Created NVE directories in $NVE_HOME"
 }
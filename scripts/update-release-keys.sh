@@ -0,0 +1,22 @@
+#!/usr/bin/env sh
+# Actualiza el keyring de claves de release de Node incrustado en el binario
+# (crates/infra/keys/nodejs-release-keys.kbx) desde un commit fijo de nodejs/release-keys.
+#
+#   scripts/update-release-keys.sh <commit>
+#
+# Revisa las huellas que se listan al final contra
+# https://github.com/nodejs/node#release-keys antes de hacer commit del fichero.
+set -eu
+
+commit="${1:?uso: $0 <commit de nodejs/release-keys>}"
+dest="$(dirname "$0")/../crates/infra/keys/nodejs-release-keys.kbx"
+url="https://raw.githubusercontent.com/nodejs/release-keys/$commit/gpg/pubring.kbx"
+
+tmp="$(mktemp)"
+trap 'rm -f "$tmp"' EXIT
+curl -fsSL "$url" -o "$tmp"
+gpg --no-default-keyring --keyring "$tmp" --list-keys --with-colons | awk -F: '$1 == "fpr" { print $10 }'
+mkdir -p "$(dirname "$dest")"
+mv "$tmp" "$dest"
+trap - EXIT
+echo "Keyring actualizado en $dest (commit $commit)"